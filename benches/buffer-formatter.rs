@@ -1,12 +1,17 @@
 use criterion::criterion_group;
 use criterion::criterion_main;
 use criterion::Criterion;
+#[cfg(feature = "base64")]
+use logged_stream::Base64Formatter;
 use logged_stream::BinaryFormatter;
 use logged_stream::BufferFormatter;
+use logged_stream::CEscapeFormatter;
 use logged_stream::DecimalFormatter;
-use logged_stream::LowercaseHexadecimalFormatter;
+use logged_stream::HexAsciiFormatter;
+use logged_stream::HexCase;
+use logged_stream::HexadecimalFormatter;
+use logged_stream::HexdumpFormatter;
 use logged_stream::OctalFormatter;
-use logged_stream::UppercaseHexadecimalFormatter;
 
 const TEST_ARRAY_LENGTH: usize = 10000;
 
@@ -25,12 +30,12 @@ const fn generate_array() -> [u8; TEST_ARRAY_LENGTH] {
 const FORMATTING_TEST_VALUES: &[u8] = &generate_array();
 
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("LowercaseHexadecimalFormatter", |b| {
-        let lowercase_hexadecimal = LowercaseHexadecimalFormatter::new_default();
+    c.bench_function("HexadecimalFormatter (lowercase)", |b| {
+        let lowercase_hexadecimal = HexadecimalFormatter::new(None, HexCase::Lower);
         b.iter(|| lowercase_hexadecimal.format_buffer(FORMATTING_TEST_VALUES))
     });
-    c.bench_function("UppercaseHexadecimalFormatter", |b| {
-        let uppercase_hexadecimal = UppercaseHexadecimalFormatter::new_default();
+    c.bench_function("HexadecimalFormatter (uppercase)", |b| {
+        let uppercase_hexadecimal = HexadecimalFormatter::new(None, HexCase::Upper);
         b.iter(|| uppercase_hexadecimal.format_buffer(FORMATTING_TEST_VALUES))
     });
     c.bench_function("DecimalFormatter", |b| {
@@ -45,6 +50,53 @@ fn criterion_benchmark(c: &mut Criterion) {
         let binary = BinaryFormatter::new_default();
         b.iter(|| binary.format_buffer(FORMATTING_TEST_VALUES))
     });
+    c.bench_function("HexdumpFormatter", |b| {
+        let hexdump = HexdumpFormatter::new();
+        b.iter(|| hexdump.format_buffer(FORMATTING_TEST_VALUES))
+    });
+    c.bench_function("HexAsciiFormatter", |b| {
+        let hex_ascii = HexAsciiFormatter::new();
+        b.iter(|| hex_ascii.format_buffer(FORMATTING_TEST_VALUES))
+    });
+    c.bench_function("CEscapeFormatter", |b| {
+        let c_escape = CEscapeFormatter::new();
+        b.iter(|| c_escape.format_buffer(FORMATTING_TEST_VALUES))
+    });
+    #[cfg(feature = "base64")]
+    c.bench_function("Base64Formatter", |b| {
+        let base64 = Base64Formatter::new_default();
+        b.iter(|| base64.format_buffer(FORMATTING_TEST_VALUES))
+    });
+    c.bench_function(
+        "HexadecimalFormatter (format_buffer_into, reused output)",
+        |b| {
+            let lowercase_hexadecimal = HexadecimalFormatter::new(None, HexCase::Lower);
+            let mut out = String::new();
+            b.iter(|| {
+                out.clear();
+                lowercase_hexadecimal.format_buffer_into(FORMATTING_TEST_VALUES, &mut out);
+            })
+        },
+    );
+    c.bench_function("HexadecimalFormatter (joined format_byte)", |b| {
+        let lowercase_hexadecimal = HexadecimalFormatter::new(None, HexCase::Lower);
+        b.iter(|| {
+            FORMATTING_TEST_VALUES
+                .iter()
+                .map(|byte| lowercase_hexadecimal.format_byte(byte))
+                .collect::<Vec<String>>()
+                .join(lowercase_hexadecimal.get_separator())
+        })
+    });
+    c.bench_function("Hexadecimal formatting (format! reference)", |b| {
+        b.iter(|| {
+            FORMATTING_TEST_VALUES
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<String>>()
+                .join(":")
+        })
+    });
 }
 
 criterion_group! {
@@ -11,10 +11,14 @@ fn criterion_benchmark(c: &mut Criterion) {
         let record_kind_filter = RecordKindFilter::new(&[RecordKind::Read]);
         b.iter(|| {
             record_kind_filter.check(&Record::new(RecordKind::Open, String::from("open")));
+            record_kind_filter.check(&Record::new(RecordKind::Connect, String::from("connect")));
             record_kind_filter.check(&Record::new(RecordKind::Read, String::from("read")));
             record_kind_filter.check(&Record::new(RecordKind::Write, String::from("write")));
             record_kind_filter.check(&Record::new(RecordKind::Error, String::from("error")));
             record_kind_filter.check(&Record::new(RecordKind::Shutdown, String::from("shutdown")));
+            record_kind_filter.check(&Record::new(RecordKind::Flush, String::from("flush")));
+            record_kind_filter.check(&Record::new(RecordKind::Seek, String::from("seek")));
+            record_kind_filter.check(&Record::new(RecordKind::Eof, String::from("eof")));
             record_kind_filter.check(&Record::new(RecordKind::Drop, String::from("drop")));
         })
     });
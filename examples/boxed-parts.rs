@@ -0,0 +1,84 @@
+use logged_stream::parse_formatter;
+use logged_stream::BufferFormatter;
+use logged_stream::ConsoleLogger;
+use logged_stream::ConsoleTarget;
+use logged_stream::DefaultFilter;
+use logged_stream::LoggedStream;
+use logged_stream::Logger;
+use logged_stream::NullLogger;
+use logged_stream::RecordFilter;
+use logged_stream::RecordKind;
+use logged_stream::RecordKindFilter;
+use std::env;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::str::FromStr;
+
+// Usage: cargo run --example boxed-parts -- [FORMAT] [KINDS] [TARGET]
+// For example: cargo run --example boxed-parts -- hex-upper read,write stdout
+
+// Every part is selected at runtime, so the type of stream doesn't depend on provided arguments.
+type BoxedStream = LoggedStream<
+    io::Cursor<Vec<u8>>,
+    Box<dyn BufferFormatter>,
+    Box<dyn RecordFilter>,
+    Box<dyn Logger>,
+>;
+
+fn select_formatter(name: &str) -> Box<dyn BufferFormatter> {
+    parse_formatter(name, None).unwrap_or_else(|| panic!("unknown format: {name}"))
+}
+
+fn select_filter(kinds: Option<&str>) -> Box<dyn RecordFilter> {
+    match kinds {
+        Some(kinds) => {
+            let kinds = kinds
+                .split(',')
+                .map(|kind| RecordKind::from_str(kind).unwrap())
+                .collect();
+            Box::new(RecordKindFilter::from_vec(kinds))
+        }
+        None => Box::new(DefaultFilter),
+    }
+}
+
+fn select_logger(target: &str) -> Box<dyn Logger> {
+    match target {
+        "stdout" => {
+            Box::new(ConsoleLogger::new_unchecked("debug").with_target(ConsoleTarget::Stdout))
+        }
+        "stderr" => {
+            Box::new(ConsoleLogger::new_unchecked("debug").with_target(ConsoleTarget::Stderr))
+        }
+        "null" => Box::new(NullLogger),
+        _ => Box::new(ConsoleLogger::new_unchecked("debug")),
+    }
+}
+
+fn main() {
+    env::set_var("RUST_LOG", "debug");
+    env_logger::builder()
+        .default_format()
+        .format_timestamp_millis()
+        .init();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let format = args.first().map_or("hex-lower", String::as_str);
+    let kinds = args.get(1).map(String::as_str);
+    let target = args.get(2).map_or("log", String::as_str);
+
+    let mut stream: BoxedStream = LoggedStream::new(
+        io::Cursor::new(Vec::new()),
+        select_formatter(format),
+        select_filter(kinds),
+        select_logger(target),
+    );
+
+    stream.write_all(b"Hello, World!").unwrap();
+    stream.flush().unwrap();
+    stream.get_mut().set_position(0);
+    let mut read = String::new();
+    stream.read_to_string(&mut read).unwrap();
+    println!("{read}");
+}
@@ -1,9 +1,8 @@
 use logged_stream::DefaultFilter;
 use logged_stream::FileLogger;
+use logged_stream::HexadecimalFormatter;
 use logged_stream::LoggedStream;
-use logged_stream::LowercaseHexadecimalFormatter;
 use std::env;
-use std::fs;
 use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
 use tokio::net;
@@ -43,9 +42,9 @@ async fn main() {
 
     let mut client = LoggedStream::new(
         net::TcpStream::connect("127.0.0.1:8080").await.unwrap(),
-        LowercaseHexadecimalFormatter::new_default(),
+        HexadecimalFormatter::new_default(),
         DefaultFilter,
-        FileLogger::new(fs::File::create("./examples/traffic.log").unwrap()),
+        FileLogger::open("./examples/traffic.log", false).unwrap(),
     );
 
     let send = [0x01, 0x02, 0x03, 0x04];
@@ -0,0 +1,66 @@
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::Request;
+use hyper::Response;
+use hyper_util::rt::TokioIo;
+use logged_stream::ConsoleLogger;
+use logged_stream::DefaultFilter;
+use logged_stream::HexadecimalFormatter;
+use logged_stream::LoggedStream;
+use std::convert::Infallible;
+use std::env;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net;
+
+async fn hello(_: Request<hyper::body::Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    Ok(Response::new(Full::new(Bytes::from("Hello, World!"))))
+}
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 4)]
+async fn main() {
+    env::set_var("RUST_LOG", "debug");
+    env_logger::builder()
+        .default_format()
+        .format_timestamp_millis()
+        .init();
+
+    let listener = net::TcpListener::bind("127.0.0.1:8080").await.unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, addr)) => {
+                    // Raw bytes of HTTP/1 connection are logged by wrapper which is passed to hyper as IO object.
+                    let stream = LoggedStream::new(
+                        stream,
+                        HexadecimalFormatter::new_default(),
+                        DefaultFilter,
+                        ConsoleLogger::new_unchecked("debug"),
+                    )
+                    .with_label(addr.to_string());
+                    tokio::spawn(async move {
+                        if let Err(err) = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service_fn(hello))
+                            .await
+                        {
+                            eprintln!("{err}");
+                        }
+                    });
+                }
+                Err(err) => panic!("{err}"),
+            }
+        }
+    });
+
+    let mut client = net::TcpStream::connect("127.0.0.1:8080").await.unwrap();
+    client
+        .write_all(b"GET / HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+    println!("{}", String::from_utf8_lossy(&response));
+}
@@ -0,0 +1,27 @@
+use logged_stream::ConsoleLogger;
+use logged_stream::ConsoleTarget;
+use logged_stream::DefaultFilter;
+use logged_stream::HexadecimalFormatter;
+use logged_stream::LoggedStream;
+use std::io::Read;
+use std::io::Write;
+
+fn main() {
+    // Copy standard input into standard output like `cat` does. Log records are written into standard error
+    // stream, so they are not mixed with copied data. Try `echo hello | cargo run --example stdio`.
+    let mut stream = LoggedStream::stdio(
+        HexadecimalFormatter::new_default(),
+        DefaultFilter,
+        ConsoleLogger::new_unchecked("debug").with_target(ConsoleTarget::Stderr),
+    );
+
+    let mut buffer = [0u8; 1024];
+    loop {
+        let length = stream.read(&mut buffer).unwrap();
+        if length == 0 {
+            break;
+        }
+        stream.write_all(&buffer[0..length]).unwrap();
+    }
+    stream.flush().unwrap();
+}
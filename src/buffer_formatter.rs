@@ -1,4 +1,21 @@
+use crate::record::RecordKind;
+#[cfg(feature = "base64")]
+use base64::engine::general_purpose;
+#[cfg(feature = "base64")]
+use base64::Engine;
+use std::fmt;
+use std::fmt::Write;
+
 const DEFAULT_SEPARATOR: &str = ":";
+const LOWERCASE_HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const UPPERCASE_HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Appends two hexadecimal digits of provided byte using provided digits table, avoiding formatting machinery.
+#[inline]
+fn push_hex_byte(byte: u8, digits: &[u8; 16], out: &mut String) {
+    out.push(char::from(digits[usize::from(byte >> 4)]));
+    out.push(char::from(digits[usize::from(byte & 0x0f)]));
+}
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Trait
@@ -19,25 +36,161 @@ pub trait BufferFormatter: Send + 'static {
     /// This method accepts one byte from buffer and format it into [`String`]. It should be implemeted manually.
     fn format_byte(&self, byte: &u8) -> String;
 
-    /// This method accepts bytes buffer and format it into [`String`]. It is automatically implemented method.
+    /// This method accepts one byte from buffer and appends its formatted representation to provided [`String`].
+    /// It is automatically implemented method which delegates to [`format_byte`], but it should be overridden
+    /// manually to avoid allocation of intermediate [`String`] per each byte.
+    ///
+    /// [`format_byte`]: BufferFormatter::format_byte
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        out.push_str(&self.format_byte(byte));
+    }
+
+    /// This method accepts bytes buffer and appends its formatted representation to provided [`String`], so the
+    /// same [`String`] can be reused between calls. It is automatically implemented method which joins bytes
+    /// formatted by [`write_byte`] with separator, but it can be overridden manually by formatters which output
+    /// layout can't be expressed this way.
+    ///
+    /// [`write_byte`]: BufferFormatter::write_byte
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        let separator = self.get_separator();
+        for (index, byte) in buffer.iter().enumerate() {
+            if index != 0 {
+                out.push_str(separator);
+            }
+            self.write_byte(byte, out);
+        }
+    }
+
+    /// This method accepts bytes buffer and writes its formatted representation into provided [`fmt::Write`]
+    /// sink, for example [`fmt::Formatter`], without building intermediate [`String`] for the whole buffer. It is
+    /// automatically implemented method which writes bytes formatted by [`write_byte`] and separator one by one,
+    /// so it should be overridden together with [`format_buffer_into`] and produce the same output.
+    ///
+    /// [`write_byte`]: BufferFormatter::write_byte
+    /// [`format_buffer_into`]: BufferFormatter::format_buffer_into
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        let separator = self.get_separator();
+        let mut formatted_byte = String::new();
+        for (index, byte) in buffer.iter().enumerate() {
+            if index != 0 {
+                out.write_str(separator)?;
+            }
+            formatted_byte.clear();
+            self.write_byte(byte, &mut formatted_byte);
+            out.write_str(&formatted_byte)?;
+        }
+        Ok(())
+    }
+
+    /// This method accepts bytes buffer and format it into [`String`]. It is automatically implemented method
+    /// which delegates to [`format_buffer_into`].
+    ///
+    /// [`format_buffer_into`]: BufferFormatter::format_buffer_into
     fn format_buffer(&self, buffer: &[u8]) -> String {
-        buffer
-            .iter()
-            .map(|b| self.format_byte(b))
-            .collect::<Vec<String>>()
-            .join(self.get_separator())
+        let mut out = String::new();
+        self.format_buffer_into(buffer, &mut out);
+        out
+    }
+
+    /// This method accepts kind of log record ([`RecordKind`]) which is being constructed and bytes buffer and
+    /// format it into [`String`]. [`LoggedStream`] calls this method instead of [`format_buffer`], so formatters
+    /// can format buffers differently depending on the direction. It is automatically implemented method which
+    /// ignores provided kind and delegates to [`format_buffer`].
+    ///
+    /// [`format_buffer`]: BufferFormatter::format_buffer
+    /// [`LoggedStream`]: crate::LoggedStream
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        let _ = kind;
+        self.format_buffer(buffer)
+    }
+
+    /// This method wraps provided bytes buffer into [`DisplayBuffer`] which implements [`fmt::Display`] trait, so
+    /// formatted bytes can be embedded into other output, for example `write!(f, "{}", formatter.display(&buf))`.
+    fn display<'a>(&'a self, buffer: &'a [u8]) -> DisplayBuffer<'a, Self>
+    where
+        Self: Sized,
+    {
+        DisplayBuffer::new(self, buffer)
     }
 }
 
-impl BufferFormatter for Box<dyn BufferFormatter> {
-    #[inline]
-    fn get_separator(&self) -> &str {
-        (**self).get_separator()
+// Trait objects with extra auto traits, such as `dyn BufferFormatter + Sync`, are distinct types, so every
+// combination needs its own implementation.
+macro_rules! impl_buffer_formatter_for_boxed {
+    ($($object:ty),+ $(,)?) => {
+        $(
+            impl BufferFormatter for Box<$object> {
+                #[inline]
+                fn get_separator(&self) -> &str {
+                    (**self).get_separator()
+                }
+
+                #[inline]
+                fn format_byte(&self, byte: &u8) -> String {
+                    (**self).format_byte(byte)
+                }
+
+                #[inline]
+                fn write_byte(&self, byte: &u8, out: &mut String) {
+                    (**self).write_byte(byte, out)
+                }
+
+                #[inline]
+                fn format_buffer(&self, buffer: &[u8]) -> String {
+                    (**self).format_buffer(buffer)
+                }
+
+                #[inline]
+                fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+                    (**self).format_buffer_into(buffer, out)
+                }
+
+                #[inline]
+                fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+                    (**self).write_buffer(buffer, out)
+                }
+
+                #[inline]
+                fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+                    (**self).format_buffer_with_kind(kind, buffer)
+                }
+            }
+        )+
+    };
+}
+
+impl_buffer_formatter_for_boxed!(
+    dyn BufferFormatter,
+    dyn BufferFormatter + Send,
+    dyn BufferFormatter + Sync,
+    dyn BufferFormatter + Send + Sync,
+);
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisplayBuffer
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Adapter which implements [`fmt::Display`] trait for borrowed bytes buffer using provided [`BufferFormatter`].
+///
+/// Bytes are formatted lazily, only when the adapter is displayed, and written straight into the destination using
+/// [`BufferFormatter::write_buffer`] method, so it can be passed to `write!` and `format!` macros directly. It is
+/// usually constructed using [`BufferFormatter::display`] method.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayBuffer<'a, F: BufferFormatter> {
+    formatter: &'a F,
+    buffer: &'a [u8],
+}
+
+impl<'a, F: BufferFormatter> DisplayBuffer<'a, F> {
+    /// Construct a new instance of [`DisplayBuffer`] using provided formatter and bytes buffer.
+    pub fn new(formatter: &'a F, buffer: &'a [u8]) -> Self {
+        Self { formatter, buffer }
     }
+}
 
-    #[inline]
-    fn format_byte(&self, byte: &u8) -> String {
-        (**self).format_byte(byte)
+impl<F: BufferFormatter> fmt::Display for DisplayBuffer<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.formatter.write_buffer(self.buffer, f)
     }
 }
 
@@ -46,9 +199,15 @@ impl BufferFormatter for Box<dyn BufferFormatter> {
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in decimal number system.
+///
+/// Bytes are not padded by default, so formatted values have different length. Use [`with_padded`] method to
+/// zero-pad every byte to 3 digits and keep columns aligned.
+///
+/// [`with_padded`]: DecimalFormatter::with_padded
 #[derive(Debug, Clone)]
 pub struct DecimalFormatter {
     separator: String,
+    padded: bool,
 }
 
 impl DecimalFormatter {
@@ -63,6 +222,7 @@ impl DecimalFormatter {
     pub fn new_owned(provided_separator: Option<String>) -> Self {
         Self {
             separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            padded: false,
         }
     }
 
@@ -70,6 +230,20 @@ impl DecimalFormatter {
     pub fn new_default() -> Self {
         Self::new_owned(None)
     }
+
+    /// Replace separator which is inserted between formatted bytes, for example to use the same separator
+    /// across several formatters constructed using default separator.
+    pub fn with_separator<T: Into<String>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Enable or disable zero-padding of every formatted byte to 3 digits, for example `005` instead of `5`.
+    /// It is disabled by default.
+    pub fn with_padded(mut self, padded: bool) -> Self {
+        self.padded = padded;
+        self
+    }
 }
 
 impl BufferFormatter for DecimalFormatter {
@@ -80,7 +254,20 @@ impl BufferFormatter for DecimalFormatter {
 
     #[inline]
     fn format_byte(&self, byte: &u8) -> String {
-        format!("{byte}")
+        if self.padded {
+            format!("{byte:03}")
+        } else {
+            format!("{byte}")
+        }
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        let _ = if self.padded {
+            write!(out, "{byte:03}")
+        } else {
+            write!(out, "{byte}")
+        };
     }
 }
 
@@ -94,6 +281,31 @@ impl BufferFormatter for Box<DecimalFormatter> {
     fn format_byte(&self, byte: &u8) -> String {
         (**self).format_byte(byte)
     }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
 }
 
 impl Default for DecimalFormatter {
@@ -107,9 +319,14 @@ impl Default for DecimalFormatter {
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in octal number system.
+///
+/// Every byte is zero-padded to 3 digits by default, use [`with_padded`] method to disable it.
+///
+/// [`with_padded`]: OctalFormatter::with_padded
 #[derive(Debug, Clone)]
 pub struct OctalFormatter {
     separator: String,
+    padded: bool,
 }
 
 impl OctalFormatter {
@@ -124,6 +341,7 @@ impl OctalFormatter {
     pub fn new_owned(provided_separator: Option<String>) -> Self {
         Self {
             separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            padded: true,
         }
     }
 
@@ -131,6 +349,19 @@ impl OctalFormatter {
     pub fn new_default() -> Self {
         Self::new_owned(None)
     }
+
+    /// Replace separator which is inserted between formatted bytes.
+    pub fn with_separator<T: Into<String>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Enable or disable zero-padding of every formatted byte to 3 digits, for example `005` instead of `5`.
+    /// It is enabled by default, disabling it gives minimal octal representation.
+    pub fn with_padded(mut self, padded: bool) -> Self {
+        self.padded = padded;
+        self
+    }
 }
 
 impl BufferFormatter for OctalFormatter {
@@ -141,7 +372,20 @@ impl BufferFormatter for OctalFormatter {
 
     #[inline]
     fn format_byte(&self, byte: &u8) -> String {
-        format!("{byte:03o}")
+        if self.padded {
+            format!("{byte:03o}")
+        } else {
+            format!("{byte:o}")
+        }
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        let _ = if self.padded {
+            write!(out, "{byte:03o}")
+        } else {
+            write!(out, "{byte:o}")
+        };
     }
 }
 
@@ -155,6 +399,31 @@ impl BufferFormatter for Box<OctalFormatter> {
     fn format_byte(&self, byte: &u8) -> String {
         (**self).format_byte(byte)
     }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
 }
 
 impl Default for OctalFormatter {
@@ -163,16 +432,159 @@ impl Default for OctalFormatter {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HexadecimalFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This enumeration represents letter case of hexadecimal digits which is used by [`HexadecimalFormatter`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum HexCase {
+    /// Uppercase digits, for example `0A`.
+    Upper,
+    /// Lowercase digits, for example `0a`.
+    #[default]
+    Lower,
+}
+
+/// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in hexadecimal number system.
+///
+/// Letter case of hexadecimal digits is selected by [`HexCase`] value provided during construction, so it can
+/// be chosen at runtime, for example from configuration flag.
+#[derive(Debug, Clone)]
+pub struct HexadecimalFormatter {
+    separator: String,
+    case: HexCase,
+}
+
+impl HexadecimalFormatter {
+    /// Construct a new instance of [`HexadecimalFormatter`] using provided borrowed separator and letter case.
+    /// In case if provided separator will be [`None`], than default separator (`:`) will be used.
+    pub fn new(provided_separator: Option<&str>, case: HexCase) -> Self {
+        Self::new_owned(provided_separator.map(ToString::to_string), case)
+    }
+
+    /// Construct a new instance of [`HexadecimalFormatter`] using provided owned separator and letter case.
+    /// In case if provided separator will be [`None`], than default separator (`:`) will be used.
+    pub fn new_owned(provided_separator: Option<String>, case: HexCase) -> Self {
+        Self {
+            separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            case,
+        }
+    }
+
+    /// Construct a new instance of [`HexadecimalFormatter`] using default separator (`:`) and lowercase letters.
+    pub fn new_default() -> Self {
+        Self::new_owned(None, HexCase::default())
+    }
+
+    /// Retrieve letter case of hexadecimal digits.
+    #[inline]
+    pub fn case(&self) -> HexCase {
+        self.case
+    }
+
+    /// Replace separator which is inserted between formatted bytes, letter case is kept.
+    pub fn with_separator<T: Into<String>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+}
+
+impl BufferFormatter for HexadecimalFormatter {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        self.separator.as_str()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        let mut out = String::with_capacity(2);
+        self.write_byte(byte, &mut out);
+        out
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        let digits = match self.case {
+            HexCase::Upper => UPPERCASE_HEX_DIGITS,
+            HexCase::Lower => LOWERCASE_HEX_DIGITS,
+        };
+        push_hex_byte(*byte, digits, out);
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        out.reserve(buffer.len() * (2 + self.separator.len()));
+        let digits = match self.case {
+            HexCase::Upper => UPPERCASE_HEX_DIGITS,
+            HexCase::Lower => LOWERCASE_HEX_DIGITS,
+        };
+        for (index, byte) in buffer.iter().enumerate() {
+            if index != 0 {
+                out.push_str(self.separator.as_str());
+            }
+            push_hex_byte(*byte, digits, out);
+        }
+    }
+}
+
+impl BufferFormatter for Box<HexadecimalFormatter> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+impl Default for HexadecimalFormatter {
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // UppercaseHexadecimalFormatter
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in hexadecimal number system.
+///
+/// It is a thin wrapper around [`HexadecimalFormatter`] with [`HexCase::Upper`] letter case which is kept
+/// for backward compatibility.
+#[deprecated(note = "use `HexadecimalFormatter` with `HexCase::Upper` instead")]
 #[derive(Debug, Clone)]
 pub struct UppercaseHexadecimalFormatter {
-    separator: String,
+    inner: HexadecimalFormatter,
 }
 
+#[allow(deprecated)]
 impl UppercaseHexadecimalFormatter {
     /// Construct a new instance of [`UppercaseHexadecimalFormatter`] using provided borrowed separator. In case
     /// if provided separator will be [`None`], than default separator (`:`) will be used.
@@ -184,7 +596,7 @@ impl UppercaseHexadecimalFormatter {
     /// if provided separator will be [`None`], than default separator (`:`) will be used.
     pub fn new_owned(provided_separator: Option<String>) -> Self {
         Self {
-            separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            inner: HexadecimalFormatter::new_owned(provided_separator, HexCase::Upper),
         }
     }
 
@@ -194,18 +606,25 @@ impl UppercaseHexadecimalFormatter {
     }
 }
 
+#[allow(deprecated)]
 impl BufferFormatter for UppercaseHexadecimalFormatter {
     #[inline]
     fn get_separator(&self) -> &str {
-        self.separator.as_str()
+        self.inner.get_separator()
     }
 
     #[inline]
     fn format_byte(&self, byte: &u8) -> String {
-        format!("{byte:02X}")
+        self.inner.format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.inner.write_byte(byte, out)
     }
 }
 
+#[allow(deprecated)]
 impl BufferFormatter for Box<UppercaseHexadecimalFormatter> {
     #[inline]
     fn get_separator(&self) -> &str {
@@ -216,8 +635,34 @@ impl BufferFormatter for Box<UppercaseHexadecimalFormatter> {
     fn format_byte(&self, byte: &u8) -> String {
         (**self).format_byte(byte)
     }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
 }
 
+#[allow(deprecated)]
 impl Default for UppercaseHexadecimalFormatter {
     fn default() -> Self {
         Self::new_default()
@@ -229,11 +674,16 @@ impl Default for UppercaseHexadecimalFormatter {
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in hexdecimal number system.
+///
+/// It is a thin wrapper around [`HexadecimalFormatter`] with [`HexCase::Lower`] letter case which is kept
+/// for backward compatibility.
+#[deprecated(note = "use `HexadecimalFormatter` with `HexCase::Lower` instead")]
 #[derive(Debug, Clone)]
 pub struct LowercaseHexadecimalFormatter {
-    separator: String,
+    inner: HexadecimalFormatter,
 }
 
+#[allow(deprecated)]
 impl LowercaseHexadecimalFormatter {
     /// Construct a new instance of [`LowercaseHexadecimalFormatter`] using provided borrowed separator. In case
     /// if provided separator will be [`None`], than default separator (`:`) will be used.
@@ -245,7 +695,7 @@ impl LowercaseHexadecimalFormatter {
     /// if provided separator will be [`None`], than default separator (`:`) will be used.
     pub fn new_owned(provided_separator: Option<String>) -> Self {
         Self {
-            separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            inner: HexadecimalFormatter::new_owned(provided_separator, HexCase::Lower),
         }
     }
 
@@ -255,18 +705,25 @@ impl LowercaseHexadecimalFormatter {
     }
 }
 
+#[allow(deprecated)]
 impl BufferFormatter for LowercaseHexadecimalFormatter {
     #[inline]
     fn get_separator(&self) -> &str {
-        self.separator.as_str()
+        self.inner.get_separator()
     }
 
     #[inline]
     fn format_byte(&self, byte: &u8) -> String {
-        format!("{byte:02x}")
+        self.inner.format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.inner.write_byte(byte, out)
     }
 }
 
+#[allow(deprecated)]
 impl BufferFormatter for Box<LowercaseHexadecimalFormatter> {
     #[inline]
     fn get_separator(&self) -> &str {
@@ -277,8 +734,34 @@ impl BufferFormatter for Box<LowercaseHexadecimalFormatter> {
     fn format_byte(&self, byte: &u8) -> String {
         (**self).format_byte(byte)
     }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
 }
 
+#[allow(deprecated)]
 impl Default for LowercaseHexadecimalFormatter {
     fn default() -> Self {
         Self::new_default()
@@ -290,9 +773,15 @@ impl Default for LowercaseHexadecimalFormatter {
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in binary number system.
+///
+/// Every byte is zero-padded to 8 digits by default, which can be disabled using [`with_padded`] method to get
+/// minimal representation.
+///
+/// [`with_padded`]: BinaryFormatter::with_padded
 #[derive(Debug, Clone)]
 pub struct BinaryFormatter {
     separator: String,
+    padded: bool,
 }
 
 impl BinaryFormatter {
@@ -307,6 +796,7 @@ impl BinaryFormatter {
     pub fn new_owned(provided_separator: Option<String>) -> Self {
         Self {
             separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            padded: true,
         }
     }
 
@@ -314,6 +804,19 @@ impl BinaryFormatter {
     pub fn new_default() -> Self {
         Self::new_owned(None)
     }
+
+    /// Replace separator which is inserted between formatted bytes.
+    pub fn with_separator<T: Into<String>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Enable or disable zero-padding of every formatted byte to 8 digits, for example `00000101` instead of `101`.
+    /// It is enabled by default.
+    pub fn with_padded(mut self, padded: bool) -> Self {
+        self.padded = padded;
+        self
+    }
 }
 
 impl BufferFormatter for BinaryFormatter {
@@ -324,7 +827,18 @@ impl BufferFormatter for BinaryFormatter {
 
     #[inline]
     fn format_byte(&self, byte: &u8) -> String {
-        format!("{byte:08b}")
+        let mut out = String::with_capacity(8);
+        self.write_byte(byte, &mut out);
+        out
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        let _ = if self.padded {
+            write!(out, "{byte:08b}")
+        } else {
+            write!(out, "{byte:b}")
+        };
     }
 }
 
@@ -338,6 +852,31 @@ impl BufferFormatter for Box<BinaryFormatter> {
     fn format_byte(&self, byte: &u8) -> String {
         (**self).format_byte(byte)
     }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
 }
 
 impl Default for BinaryFormatter {
@@ -347,24 +886,1196 @@ impl Default for BinaryFormatter {
 }
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// Tests
+// HexdumpFormatter
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[cfg(test)]
-mod tests {
-    use crate::buffer_formatter::BinaryFormatter;
-    use crate::buffer_formatter::BufferFormatter;
-    use crate::buffer_formatter::DecimalFormatter;
-    use crate::buffer_formatter::LowercaseHexadecimalFormatter;
-    use crate::buffer_formatter::OctalFormatter;
-    use crate::buffer_formatter::UppercaseHexadecimalFormatter;
+/// This implementation of [`BufferFormatter`] trait formats provided bytes buffer in canonical hex+ASCII
+/// layout, the same as `hexdump -C` command does.
+///
+/// Each output line starts with an offset column, followed by up to 16 space-separated lowercase hexadecimal
+/// bytes split into two groups of 8 and the ASCII representation of these bytes enclosed in `|` characters.
+/// Non-printable bytes are displayed as `.` inside ASCII column. The last line contains only the total length
+/// of the formatted buffer. Unlike `hexdump -C`, repeated lines are not collapsed into `*`.
+///
+/// ```text
+/// 00000000  68 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |hello, world!.|
+/// 0000000e
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HexdumpFormatter;
+
+impl HexdumpFormatter {
+    const BYTES_PER_LINE: usize = 16;
+    const BYTES_PER_GROUP: usize = 8;
+
+    /// Construct a new instance of [`HexdumpFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
 
-    const FORMATTING_TEST_VALUES: &[u8] = &[10, 11, 12, 13, 14, 15, 16, 17, 18];
+impl BufferFormatter for HexdumpFormatter {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        " "
+    }
 
-    #[test]
-    fn test_buffer_formatting() {
-        let lowercase_hexadecimal = LowercaseHexadecimalFormatter::new_default();
-        let uppercase_hexadecimal = UppercaseHexadecimalFormatter::new_default();
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        let mut out = String::with_capacity(2);
+        self.write_byte(byte, &mut out);
+        out
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        push_hex_byte(*byte, LOWERCASE_HEX_DIGITS, out);
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        for (index, chunk) in buffer.chunks(Self::BYTES_PER_LINE).enumerate() {
+            let _ = write!(out, "{:08x} ", index * Self::BYTES_PER_LINE);
+            for position in 0..Self::BYTES_PER_LINE {
+                if position % Self::BYTES_PER_GROUP == 0 {
+                    out.push(' ');
+                }
+                match chunk.get(position) {
+                    Some(byte) => {
+                        self.write_byte(byte, out);
+                        out.push_str(self.get_separator());
+                    }
+                    None => out.push_str("   "),
+                }
+            }
+            out.push_str(" |");
+            out.extend(chunk.iter().map(|byte| match byte {
+                0x20..=0x7e => char::from(*byte),
+                _ => '.',
+            }));
+            out.push_str("|\n");
+        }
+
+        let _ = write!(out, "{:08x}", buffer.len());
+    }
+
+    // Lines are laid out across the whole buffer, so they are formatted using the same code path as
+    // `format_buffer_into`.
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.format_buffer(buffer))
+    }
+}
+
+impl BufferFormatter for Box<HexdumpFormatter> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HexAsciiFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait formats provided bytes buffer as a single line of
+/// space-separated lowercase hexadecimal bytes followed by `|` character and ASCII representation of the same
+/// bytes. Non-printable bytes are displayed as `.` inside ASCII part. Unlike [`HexdumpFormatter`], output has
+/// no offset column and is not split into lines.
+///
+/// ```text
+/// 68 65 6c 6c 6f 0a | hello.
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HexAsciiFormatter;
+
+impl HexAsciiFormatter {
+    /// Construct a new instance of [`HexAsciiFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl BufferFormatter for HexAsciiFormatter {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        " "
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        let mut out = String::with_capacity(2);
+        self.write_byte(byte, &mut out);
+        out
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        push_hex_byte(*byte, LOWERCASE_HEX_DIGITS, out);
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        if buffer.is_empty() {
+            return;
+        }
+
+        for byte in buffer {
+            self.write_byte(byte, out);
+            out.push_str(self.get_separator());
+        }
+        out.push_str("| ");
+        out.extend(buffer.iter().map(|byte| match byte {
+            0x20..=0x7e => char::from(*byte),
+            _ => '.',
+        }));
+    }
+
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+
+        let mut formatted_byte = String::with_capacity(2);
+        for byte in buffer {
+            formatted_byte.clear();
+            self.write_byte(byte, &mut formatted_byte);
+            out.write_str(&formatted_byte)?;
+            out.write_str(self.get_separator())?;
+        }
+        out.write_str("| ")?;
+        for byte in buffer {
+            out.write_char(match byte {
+                0x20..=0x7e => char::from(*byte),
+                _ => '.',
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl BufferFormatter for Box<HexAsciiFormatter> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// CEscapeFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait formats provided bytes buffer as double-quoted C string
+/// literal, which is convenient for embedding captured payloads into source code or test fixtures. Printable
+/// ASCII bytes are displayed as is, `\n`, `\t`, `\r`, `\\` and `\"` are escaped using their common escape
+/// sequences and all other bytes are escaped as `\xNN`.
+///
+/// ```text
+/// "\x01\x02abc\n"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CEscapeFormatter;
+
+impl CEscapeFormatter {
+    /// Construct a new instance of [`CEscapeFormatter`].
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl BufferFormatter for CEscapeFormatter {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        ""
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        let mut out = String::with_capacity(4);
+        self.write_byte(byte, &mut out);
+        out
+    }
+
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        match byte {
+            b'\n' => out.push_str("\\n"),
+            b'\t' => out.push_str("\\t"),
+            b'\r' => out.push_str("\\r"),
+            b'\\' => out.push_str("\\\\"),
+            b'"' => out.push_str("\\\""),
+            0x20..=0x7e => out.push(char::from(*byte)),
+            _ => {
+                out.push_str("\\x");
+                push_hex_byte(*byte, LOWERCASE_HEX_DIGITS, out);
+            }
+        }
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        out.push('"');
+        for byte in buffer {
+            self.write_byte(byte, out);
+        }
+        out.push('"');
+    }
+
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        let mut formatted_byte = String::with_capacity(4);
+        out.write_char('"')?;
+        for byte in buffer {
+            formatted_byte.clear();
+            self.write_byte(byte, &mut formatted_byte);
+            out.write_str(&formatted_byte)?;
+        }
+        out.write_char('"')
+    }
+}
+
+impl BufferFormatter for Box<CEscapeFormatter> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Base64Formatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This enumeration represents an alphabet which is used by [`Base64Formatter`] during encoding.
+#[cfg(feature = "base64")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Base64Alphabet {
+    /// Standard alphabet with `+` and `/` characters, as defined in RFC 4648.
+    #[default]
+    Standard,
+    /// URL-safe alphabet with `-` and `_` characters, as defined in RFC 4648.
+    UrlSafe,
+}
+
+/// This implementation of [`BufferFormatter`] trait encodes provided bytes buffer into padded base64 string.
+///
+/// Unlike other formatters, it encodes the whole buffer at once instead of formatting each byte separately,
+/// so formatted output is much more compact than hexadecimal one. The alphabet which is used for encoding
+/// is selected by [`Base64Alphabet`] value provided during construction. It is available only when `base64`
+/// feature is enabled.
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, Default)]
+pub struct Base64Formatter {
+    alphabet: Base64Alphabet,
+}
+
+#[cfg(feature = "base64")]
+impl Base64Formatter {
+    /// Construct a new instance of [`Base64Formatter`] using provided alphabet.
+    pub fn new(alphabet: Base64Alphabet) -> Self {
+        Self { alphabet }
+    }
+
+    /// Construct a new instance of [`Base64Formatter`] using standard alphabet.
+    pub fn new_default() -> Self {
+        Self::new(Base64Alphabet::Standard)
+    }
+
+    fn encode(&self, buffer: &[u8], out: &mut String) {
+        match self.alphabet {
+            Base64Alphabet::Standard => general_purpose::STANDARD.encode_string(buffer, out),
+            Base64Alphabet::UrlSafe => general_purpose::URL_SAFE.encode_string(buffer, out),
+        }
+    }
+}
+
+#[cfg(feature = "base64")]
+impl BufferFormatter for Base64Formatter {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        ""
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        self.format_buffer(std::slice::from_ref(byte))
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.encode(std::slice::from_ref(byte), out)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        self.encode(buffer, out)
+    }
+
+    // Every output character depends on several input bytes, so the buffer is encoded at once.
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.format_buffer(buffer))
+    }
+}
+
+#[cfg(feature = "base64")]
+impl BufferFormatter for Box<Base64Formatter> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TruncatingFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait limits the number of bytes which are rendered per one
+/// formatted buffer.
+///
+/// It wraps another [`BufferFormatter`] implementation and delegates formatting to it, but only the first
+/// `max_bytes` bytes of provided buffer are formatted. If some bytes were omitted, then suffix containing
+/// their real count is appended to the output, for example `0a:0b:0c … (+65000 more bytes)`. In case if
+/// `max_bytes` is [`None`], the whole buffer is formatted.
+#[derive(Debug, Clone)]
+pub struct TruncatingFormatter<F: BufferFormatter> {
+    inner: F,
+    max_bytes: Option<usize>,
+}
+
+impl<F: BufferFormatter> TruncatingFormatter<F> {
+    /// Construct a new instance of [`TruncatingFormatter`] using provided inner formatter and max number of
+    /// rendered bytes.
+    pub fn new(inner: F, max_bytes: Option<usize>) -> Self {
+        Self { inner, max_bytes }
+    }
+
+    /// Retrieve a reference to the inner formatter.
+    #[inline]
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Retrieve max number of rendered bytes.
+    #[inline]
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+}
+
+impl<F: BufferFormatter> BufferFormatter for TruncatingFormatter<F> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        self.inner.get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        self.inner.format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.inner.write_byte(byte, out)
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        let max_bytes = match self.max_bytes {
+            Some(max_bytes) if buffer.len() > max_bytes => max_bytes,
+            _ => return self.inner.format_buffer_into(buffer, out),
+        };
+
+        let omitted = buffer.len() - max_bytes;
+        let length_before = out.len();
+        self.inner.format_buffer_into(&buffer[..max_bytes], out);
+        if out.len() != length_before {
+            out.push(' ');
+        }
+        let _ = write!(out, "… (+{omitted} more bytes)");
+    }
+
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        match self.max_bytes {
+            Some(max_bytes) if buffer.len() > max_bytes => {
+                // Kept part is bounded by `max_bytes`, so formatting it separately is cheap.
+                out.write_str(&self.format_buffer(buffer))
+            }
+            _ => self.inner.write_buffer(buffer, out),
+        }
+    }
+
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        let max_bytes = match self.max_bytes {
+            Some(max_bytes) if buffer.len() > max_bytes => max_bytes,
+            _ => return self.inner.format_buffer_with_kind(kind, buffer),
+        };
+
+        let omitted = buffer.len() - max_bytes;
+        let mut out = self
+            .inner
+            .format_buffer_with_kind(kind, &buffer[..max_bytes]);
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        let _ = write!(out, "… (+{omitted} more bytes)");
+        out
+    }
+}
+
+impl<F: BufferFormatter> BufferFormatter for Box<TruncatingFormatter<F>> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// GroupingFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait groups formatted bytes into fixed-width words.
+///
+/// It wraps another [`BufferFormatter`] implementation and inserts group separator after every `group_size`
+/// formatted bytes, while bytes inside one group are still separated by the inner formatter separator. For
+/// example, [`HexadecimalFormatter`] with empty separator, group size `4` and group separator ` | `
+/// formats bytes as `01020304 | 05060708`. In case if group size is `0`, the output is the same as the
+/// inner formatter output.
+#[derive(Debug, Clone)]
+pub struct GroupingFormatter<F: BufferFormatter> {
+    inner: F,
+    group_size: usize,
+    group_separator: String,
+}
+
+impl<F: BufferFormatter> GroupingFormatter<F> {
+    /// Construct a new instance of [`GroupingFormatter`] using provided inner formatter, group size and
+    /// borrowed group separator.
+    pub fn new(inner: F, group_size: usize, group_separator: &str) -> Self {
+        Self::new_owned(inner, group_size, group_separator.to_string())
+    }
+
+    /// Construct a new instance of [`GroupingFormatter`] using provided inner formatter, group size and
+    /// owned group separator.
+    pub fn new_owned(inner: F, group_size: usize, group_separator: String) -> Self {
+        Self {
+            inner,
+            group_size,
+            group_separator,
+        }
+    }
+
+    /// Retrieve a reference to the inner formatter.
+    #[inline]
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Retrieve the number of bytes in one group.
+    #[inline]
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// Retrieve the separator which is inserted between groups.
+    #[inline]
+    pub fn group_separator(&self) -> &str {
+        self.group_separator.as_str()
+    }
+}
+
+impl<F: BufferFormatter> BufferFormatter for GroupingFormatter<F> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        self.inner.get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        self.inner.format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.inner.write_byte(byte, out)
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        if self.group_size == 0 {
+            return self.inner.format_buffer_into(buffer, out);
+        }
+
+        for (index, group) in buffer.chunks(self.group_size).enumerate() {
+            if index != 0 {
+                out.push_str(self.group_separator.as_str());
+            }
+            self.inner.format_buffer_into(group, out);
+        }
+    }
+
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        if self.group_size == 0 {
+            return self.inner.write_buffer(buffer, out);
+        }
+
+        for (index, group) in buffer.chunks(self.group_size).enumerate() {
+            if index != 0 {
+                out.write_str(self.group_separator.as_str())?;
+            }
+            self.inner.write_buffer(group, out)?;
+        }
+        Ok(())
+    }
+
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        if self.group_size == 0 {
+            return self.inner.format_buffer_with_kind(kind, buffer);
+        }
+
+        buffer
+            .chunks(self.group_size)
+            .map(|group| self.inner.format_buffer_with_kind(kind, group))
+            .collect::<Vec<_>>()
+            .join(self.group_separator.as_str())
+    }
+}
+
+impl<F: BufferFormatter> BufferFormatter for Box<GroupingFormatter<F>> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrefixSplitFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait visually separates fixed-length prefix of provided bytes
+/// buffer, for example length prefix of a frame, from the remaining payload.
+///
+/// It wraps two other [`BufferFormatter`] implementations: the first `prefix_len` bytes are formatted using prefix
+/// formatter and the remaining bytes using payload formatter, so each part can have its own style and separator.
+/// Formatted parts are joined with provided delimiter. For example, [`DecimalFormatter`] as prefix formatter and
+/// [`HexadecimalFormatter`] as payload formatter with prefix length `2` and delimiter ` || ` format bytes as
+/// `0:3 || 61:62:63`. Buffers which are not longer than prefix length are formatted entirely as prefix, while zero
+/// prefix length formats the whole buffer as payload, both without delimiter.
+#[derive(Debug, Clone)]
+pub struct PrefixSplitFormatter<P: BufferFormatter, F: BufferFormatter> {
+    prefix: P,
+    payload: F,
+    prefix_len: usize,
+    delimiter: String,
+}
+
+impl<P: BufferFormatter, F: BufferFormatter> PrefixSplitFormatter<P, F> {
+    /// Construct a new instance of [`PrefixSplitFormatter`] using provided prefix and payload formatters, prefix
+    /// length and borrowed delimiter.
+    pub fn new(prefix: P, payload: F, prefix_len: usize, delimiter: &str) -> Self {
+        Self::new_owned(prefix, payload, prefix_len, delimiter.to_string())
+    }
+
+    /// Construct a new instance of [`PrefixSplitFormatter`] using provided prefix and payload formatters, prefix
+    /// length and owned delimiter.
+    pub fn new_owned(prefix: P, payload: F, prefix_len: usize, delimiter: String) -> Self {
+        Self {
+            prefix,
+            payload,
+            prefix_len,
+            delimiter,
+        }
+    }
+
+    /// Retrieve a reference to the formatter of prefix bytes.
+    #[inline]
+    pub fn prefix_formatter(&self) -> &P {
+        &self.prefix
+    }
+
+    /// Retrieve a reference to the formatter of payload bytes.
+    #[inline]
+    pub fn payload_formatter(&self) -> &F {
+        &self.payload
+    }
+
+    /// Retrieve the number of bytes in prefix.
+    #[inline]
+    pub fn prefix_len(&self) -> usize {
+        self.prefix_len
+    }
+
+    /// Retrieve the delimiter which is inserted between prefix and payload.
+    #[inline]
+    pub fn delimiter(&self) -> &str {
+        self.delimiter.as_str()
+    }
+}
+
+/// Separator and single bytes are taken from payload formatter, since payload usually makes up most of the buffer.
+impl<P: BufferFormatter, F: BufferFormatter> BufferFormatter for PrefixSplitFormatter<P, F> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        self.payload.get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        self.payload.format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.payload.write_byte(byte, out)
+    }
+
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        if self.prefix_len == 0 {
+            return self.payload.format_buffer_into(buffer, out);
+        }
+        if buffer.len() <= self.prefix_len {
+            return self.prefix.format_buffer_into(buffer, out);
+        }
+
+        let (prefix, payload) = buffer.split_at(self.prefix_len);
+        self.prefix.format_buffer_into(prefix, out);
+        out.push_str(self.delimiter.as_str());
+        self.payload.format_buffer_into(payload, out);
+    }
+
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        if self.prefix_len == 0 {
+            return self.payload.write_buffer(buffer, out);
+        }
+        if buffer.len() <= self.prefix_len {
+            return self.prefix.write_buffer(buffer, out);
+        }
+
+        let (prefix, payload) = buffer.split_at(self.prefix_len);
+        self.prefix.write_buffer(prefix, out)?;
+        out.write_str(self.delimiter.as_str())?;
+        self.payload.write_buffer(payload, out)
+    }
+
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        if self.prefix_len == 0 {
+            return self.payload.format_buffer_with_kind(kind, buffer);
+        }
+        if buffer.len() <= self.prefix_len {
+            return self.prefix.format_buffer_with_kind(kind, buffer);
+        }
+
+        let (prefix, payload) = buffer.split_at(self.prefix_len);
+        let mut out = self.prefix.format_buffer_with_kind(kind, prefix);
+        out.push_str(self.delimiter.as_str());
+        out.push_str(&self.payload.format_buffer_with_kind(kind, payload));
+        out
+    }
+}
+
+impl<P: BufferFormatter, F: BufferFormatter> BufferFormatter for Box<PrefixSplitFormatter<P, F>> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FnFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait formats each byte of provided bytes buffer using closure
+/// provided during construction.
+///
+/// It allows to quickly define custom bytes representation without implementing [`BufferFormatter`] trait for
+/// a new structure manually.
+pub struct FnFormatter {
+    separator: String,
+    format: Box<dyn Fn(&u8) -> String + Send>,
+}
+
+impl FnFormatter {
+    /// Construct a new instance of [`FnFormatter`] using provided borrowed separator and byte formatting closure.
+    /// In case if provided separator will be [`None`], than default separator (`:`) will be used.
+    pub fn new<F>(provided_separator: Option<&str>, format: F) -> Self
+    where
+        F: Fn(&u8) -> String + Send + 'static,
+    {
+        Self::new_owned(provided_separator.map(ToString::to_string), format)
+    }
+
+    /// Construct a new instance of [`FnFormatter`] using provided owned separator and byte formatting closure.
+    /// In case if provided separator will be [`None`], than default separator (`:`) will be used.
+    pub fn new_owned<F>(provided_separator: Option<String>, format: F) -> Self
+    where
+        F: Fn(&u8) -> String + Send + 'static,
+    {
+        Self {
+            separator: provided_separator.unwrap_or(DEFAULT_SEPARATOR.to_string()),
+            format: Box::new(format),
+        }
+    }
+
+    /// Replace separator which is inserted between bytes formatted by closure.
+    pub fn with_separator<T: Into<String>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
+        self
+    }
+}
+
+impl fmt::Debug for FnFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnFormatter")
+            .field("separator", &self.separator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BufferFormatter for FnFormatter {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        self.separator.as_str()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (self.format)(byte)
+    }
+}
+
+impl BufferFormatter for Box<FnFormatter> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DirectionalFormatter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`BufferFormatter`] trait uses separate formatters for read and written bytes.
+///
+/// Bytes of log records with [`Read`] kind are formatted by the read formatter, while bytes of log records with
+/// any other kind are formatted by the write formatter. It is useful for protocols which use different
+/// encodings in different directions, for example binary requests and text responses. When kind of log record
+/// is unknown, for example during [`format_buffer`] method call, the write formatter is used.
+///
+/// [`Read`]: RecordKind::Read
+/// [`format_buffer`]: BufferFormatter::format_buffer
+#[derive(Debug, Clone)]
+pub struct DirectionalFormatter<R: BufferFormatter, W: BufferFormatter> {
+    read: R,
+    write: W,
+}
+
+impl<R: BufferFormatter, W: BufferFormatter> DirectionalFormatter<R, W> {
+    /// Construct a new instance of [`DirectionalFormatter`] using provided read and write formatters.
+    pub fn new(read: R, write: W) -> Self {
+        Self { read, write }
+    }
+
+    /// Retrieve a reference to the formatter of read bytes.
+    #[inline]
+    pub fn read(&self) -> &R {
+        &self.read
+    }
+
+    /// Retrieve a reference to the formatter of written bytes.
+    #[inline]
+    pub fn write(&self) -> &W {
+        &self.write
+    }
+}
+
+impl<R: BufferFormatter, W: BufferFormatter> BufferFormatter for DirectionalFormatter<R, W> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        self.write.get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        self.write.format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        self.write.write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        self.write.format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        self.write.write_buffer(buffer, out)
+    }
+
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        match kind {
+            RecordKind::Read => self.read.format_buffer_with_kind(kind, buffer),
+            _ => self.write.format_buffer_with_kind(kind, buffer),
+        }
+    }
+}
+
+impl<R: BufferFormatter, W: BufferFormatter> BufferFormatter for Box<DirectionalFormatter<R, W>> {
+    #[inline]
+    fn get_separator(&self) -> &str {
+        (**self).get_separator()
+    }
+
+    #[inline]
+    fn format_byte(&self, byte: &u8) -> String {
+        (**self).format_byte(byte)
+    }
+
+    #[inline]
+    fn write_byte(&self, byte: &u8, out: &mut String) {
+        (**self).write_byte(byte, out)
+    }
+
+    #[inline]
+    fn format_buffer(&self, buffer: &[u8]) -> String {
+        (**self).format_buffer(buffer)
+    }
+
+    #[inline]
+    fn format_buffer_into(&self, buffer: &[u8], out: &mut String) {
+        (**self).format_buffer_into(buffer, out)
+    }
+
+    #[inline]
+    fn write_buffer(&self, buffer: &[u8], out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_buffer(buffer, out)
+    }
+
+    #[inline]
+    fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+        (**self).format_buffer_with_kind(kind, buffer)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Parsing
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Constructs boxed [`BufferFormatter`] implementation by its name, which is useful to select formatting part
+/// from command line argument or configuration file. Name matching is case-insensitive and ignores surrounding
+/// whitespace. Returns [`None`] in case if provided name is unknown.
+///
+/// The following names are supported:
+///
+/// -   `hex-lower` and `hex-upper`: [`HexadecimalFormatter`] with lowercase and uppercase letters respectively.
+/// -   `decimal`: [`DecimalFormatter`].
+/// -   `octal`: [`OctalFormatter`].
+/// -   `binary`: [`BinaryFormatter`].
+/// -   `hexdump`: [`HexdumpFormatter`].
+/// -   `hex-ascii`: [`HexAsciiFormatter`].
+/// -   `c-escape`: [`CEscapeFormatter`].
+/// -   `base64` and `base64-url`: `Base64Formatter` with standard and URL-safe alphabets respectively (requires
+///     `base64` feature).
+///
+/// Provided separator is used by formatters which join formatted bytes with it, in case if it will be [`None`],
+/// than default separator (`:`) will be used. Other formatters ignore it.
+pub fn parse_formatter(name: &str, separator: Option<&str>) -> Option<Box<dyn BufferFormatter>> {
+    let formatter: Box<dyn BufferFormatter> = match name.trim().to_ascii_lowercase().as_str() {
+        "hex-lower" => Box::new(HexadecimalFormatter::new(separator, HexCase::Lower)),
+        "hex-upper" => Box::new(HexadecimalFormatter::new(separator, HexCase::Upper)),
+        "decimal" => Box::new(DecimalFormatter::new(separator)),
+        "octal" => Box::new(OctalFormatter::new(separator)),
+        "binary" => Box::new(BinaryFormatter::new(separator)),
+        "hexdump" => Box::new(HexdumpFormatter::new()),
+        "hex-ascii" => Box::new(HexAsciiFormatter::new()),
+        "c-escape" => Box::new(CEscapeFormatter::new()),
+        #[cfg(feature = "base64")]
+        "base64" => Box::new(Base64Formatter::new(Base64Alphabet::Standard)),
+        #[cfg(feature = "base64")]
+        "base64-url" => Box::new(Base64Formatter::new(Base64Alphabet::UrlSafe)),
+        _ => return None,
+    };
+    Some(formatter)
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use crate::buffer_formatter::parse_formatter;
+    #[cfg(feature = "base64")]
+    use crate::buffer_formatter::Base64Alphabet;
+    #[cfg(feature = "base64")]
+    use crate::buffer_formatter::Base64Formatter;
+    use crate::buffer_formatter::BinaryFormatter;
+    use crate::buffer_formatter::BufferFormatter;
+    use crate::buffer_formatter::CEscapeFormatter;
+    use crate::buffer_formatter::DecimalFormatter;
+    use crate::buffer_formatter::DirectionalFormatter;
+    use crate::buffer_formatter::DisplayBuffer;
+    use crate::buffer_formatter::FnFormatter;
+    use crate::buffer_formatter::GroupingFormatter;
+    use crate::buffer_formatter::HexAsciiFormatter;
+    use crate::buffer_formatter::HexCase;
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::buffer_formatter::HexdumpFormatter;
+    use crate::buffer_formatter::LowercaseHexadecimalFormatter;
+    use crate::buffer_formatter::OctalFormatter;
+    use crate::buffer_formatter::PrefixSplitFormatter;
+    use crate::buffer_formatter::TruncatingFormatter;
+    use crate::buffer_formatter::UppercaseHexadecimalFormatter;
+    use crate::record::RecordKind;
+    #[cfg(feature = "base64")]
+    use base64::engine::general_purpose;
+    #[cfg(feature = "base64")]
+    use base64::Engine;
+
+    const FORMATTING_TEST_VALUES: &[u8] = &[10, 11, 12, 13, 14, 15, 16, 17, 18];
+
+    #[test]
+    fn test_buffer_formatting() {
+        let lowercase_hexadecimal = LowercaseHexadecimalFormatter::new_default();
+        let uppercase_hexadecimal = UppercaseHexadecimalFormatter::new_default();
         let decimal = DecimalFormatter::new_default();
         let octal = OctalFormatter::new_default();
         let binary = BinaryFormatter::new_default();
@@ -425,8 +2136,673 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hexadecimal_formatting() {
+        let lowercase = HexadecimalFormatter::new(None, HexCase::Lower);
+        let uppercase = HexadecimalFormatter::new(Some("-"), HexCase::Upper);
+
+        assert_eq!(
+            lowercase.format_buffer(FORMATTING_TEST_VALUES),
+            LowercaseHexadecimalFormatter::new_default().format_buffer(FORMATTING_TEST_VALUES)
+        );
+        assert_eq!(
+            uppercase.format_buffer(FORMATTING_TEST_VALUES),
+            UppercaseHexadecimalFormatter::new(Some("-")).format_buffer(FORMATTING_TEST_VALUES)
+        );
+        assert_eq!(
+            HexadecimalFormatter::new_default().format_buffer(FORMATTING_TEST_VALUES),
+            String::from("0a:0b:0c:0d:0e:0f:10:11:12")
+        );
+        assert_eq!(
+            uppercase.format_buffer(FORMATTING_TEST_VALUES),
+            String::from("0A-0B-0C-0D-0E-0F-10-11-12")
+        );
+    }
+
+    #[test]
+    fn test_hexadecimal_formatting_all_bytes() {
+        let lowercase = HexadecimalFormatter::new_default();
+        let uppercase = HexadecimalFormatter::new(None, HexCase::Upper);
+        let hexdump = HexdumpFormatter::new();
+
+        for byte in 0..=u8::MAX {
+            assert_eq!(lowercase.format_byte(&byte), format!("{byte:02x}"));
+            assert_eq!(uppercase.format_byte(&byte), format!("{byte:02X}"));
+            assert_eq!(hexdump.format_byte(&byte), format!("{byte:02x}"));
+        }
+
+        let all_bytes = (0..=u8::MAX).collect::<Vec<u8>>();
+        let reference = |format: fn(&u8) -> String| {
+            all_bytes
+                .iter()
+                .map(format)
+                .collect::<Vec<String>>()
+                .join(":")
+        };
+        assert_eq!(
+            lowercase.format_buffer(&all_bytes),
+            reference(|byte| format!("{byte:02x}"))
+        );
+        assert_eq!(
+            uppercase.format_buffer(&all_bytes),
+            reference(|byte| format!("{byte:02X}"))
+        );
+    }
+
+    #[test]
+    fn test_hexdump_formatting() {
+        let hexdump = HexdumpFormatter::new();
+        let buffer = (0..=255).collect::<Vec<u8>>();
+
+        assert_eq!(
+            hexdump.format_buffer(&buffer),
+            [
+                "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  |................|",
+                "00000010  10 11 12 13 14 15 16 17  18 19 1a 1b 1c 1d 1e 1f  |................|",
+                r##"00000020  20 21 22 23 24 25 26 27  28 29 2a 2b 2c 2d 2e 2f  | !"#$%&'()*+,-./|"##,
+                "00000030  30 31 32 33 34 35 36 37  38 39 3a 3b 3c 3d 3e 3f  |0123456789:;<=>?|",
+                "00000040  40 41 42 43 44 45 46 47  48 49 4a 4b 4c 4d 4e 4f  |@ABCDEFGHIJKLMNO|",
+                r"00000050  50 51 52 53 54 55 56 57  58 59 5a 5b 5c 5d 5e 5f  |PQRSTUVWXYZ[\]^_|",
+                "00000060  60 61 62 63 64 65 66 67  68 69 6a 6b 6c 6d 6e 6f  |`abcdefghijklmno|",
+                "00000070  70 71 72 73 74 75 76 77  78 79 7a 7b 7c 7d 7e 7f  |pqrstuvwxyz{|}~.|",
+                "00000080  80 81 82 83 84 85 86 87  88 89 8a 8b 8c 8d 8e 8f  |................|",
+                "00000090  90 91 92 93 94 95 96 97  98 99 9a 9b 9c 9d 9e 9f  |................|",
+                "000000a0  a0 a1 a2 a3 a4 a5 a6 a7  a8 a9 aa ab ac ad ae af  |................|",
+                "000000b0  b0 b1 b2 b3 b4 b5 b6 b7  b8 b9 ba bb bc bd be bf  |................|",
+                "000000c0  c0 c1 c2 c3 c4 c5 c6 c7  c8 c9 ca cb cc cd ce cf  |................|",
+                "000000d0  d0 d1 d2 d3 d4 d5 d6 d7  d8 d9 da db dc dd de df  |................|",
+                "000000e0  e0 e1 e2 e3 e4 e5 e6 e7  e8 e9 ea eb ec ed ee ef  |................|",
+                "000000f0  f0 f1 f2 f3 f4 f5 f6 f7  f8 f9 fa fb fc fd fe ff  |................|",
+                "00000100",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_hexdump_partial_line() {
+        let hexdump = HexdumpFormatter::new();
+
+        assert_eq!(
+            hexdump.format_buffer(b"ABCDEFGHIJKLMNOPQRST"),
+            [
+                "00000000  41 42 43 44 45 46 47 48  49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|",
+                "00000010  51 52 53 54                                       |QRST|",
+                "00000014",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            hexdump.format_buffer(b"hello"),
+            [
+                "00000000  68 65 6c 6c 6f                                    |hello|",
+                "00000005",
+            ]
+            .join("\n")
+        );
+        assert_eq!(hexdump.format_buffer(&[]), String::new());
+
+        // Boxed formatter must use overridden buffer formatting.
+        let boxed: Box<dyn BufferFormatter> = Box::new(HexdumpFormatter::new());
+        assert_eq!(
+            boxed.format_buffer(b"hello"),
+            hexdump.format_buffer(b"hello")
+        );
+    }
+
+    #[test]
+    fn test_hex_ascii_formatting() {
+        let hex_ascii = HexAsciiFormatter::new();
+
+        assert_eq!(
+            hex_ascii.format_buffer(b"hi\x00\x7f\n~ \xff"),
+            String::from("68 69 00 7f 0a 7e 20 ff | hi...~ .")
+        );
+        assert_eq!(hex_ascii.format_byte(&0x0a), String::from("0a"));
+        assert_eq!(hex_ascii.format_buffer(&[]), String::new());
+
+        // Boxed formatter must use overridden buffer formatting.
+        let boxed: Box<dyn BufferFormatter> = Box::new(HexAsciiFormatter::new());
+        assert_eq!(
+            boxed.format_buffer(b"hello"),
+            String::from("68 65 6c 6c 6f | hello")
+        );
+    }
+
+    #[test]
+    fn test_with_separator() {
+        let buffer = [0x0a, 0x0b];
+        assert_eq!(
+            DecimalFormatter::new_default()
+                .with_separator(" ")
+                .format_buffer(&buffer),
+            "10 11"
+        );
+        assert_eq!(
+            OctalFormatter::new_default()
+                .with_separator(String::from("-"))
+                .format_buffer(&buffer),
+            "012-013"
+        );
+        let hexadecimal = HexadecimalFormatter::new(None, HexCase::Upper).with_separator("");
+        assert_eq!(hexadecimal.format_buffer(&buffer), "0A0B");
+        assert_eq!(hexadecimal.case(), HexCase::Upper);
+        assert_eq!(
+            BinaryFormatter::new(Some(":"))
+                .with_separator(", ")
+                .format_buffer(&buffer),
+            "00001010, 00001011"
+        );
+        let function = FnFormatter::new(None, |byte| format!("{byte}")).with_separator("/");
+        assert_eq!(function.get_separator(), "/");
+        assert_eq!(function.format_buffer(&buffer), "10/11");
+    }
+
+    #[test]
+    fn test_decimal_and_octal_padding() {
+        let buffer = [5, 42, 200];
+
+        let decimal = DecimalFormatter::new_default();
+        assert_eq!(decimal.format_buffer(&buffer), "5:42:200");
+        let decimal = decimal.with_padded(true);
+        assert_eq!(decimal.format_buffer(&buffer), "005:042:200");
+        assert_eq!(decimal.format_byte(&5), "005");
+
+        let octal = OctalFormatter::new_default();
+        assert_eq!(octal.format_buffer(&buffer), "005:052:310");
+        let octal = octal.with_padded(false);
+        assert_eq!(octal.format_buffer(&buffer), "5:52:310");
+        assert_eq!(octal.format_byte(&5), "5");
+    }
+
+    #[test]
+    fn test_binary_padding() {
+        let buffer = [5, 200, 0];
+        let padded = BinaryFormatter::new_default();
+        assert_eq!(padded.format_buffer(&buffer), "00000101:11001000:00000000");
+        assert_eq!(padded.format_byte(&5), "00000101");
+
+        let unpadded = BinaryFormatter::new_default().with_padded(false);
+        assert_eq!(unpadded.format_buffer(&buffer), "101:11001000:0");
+        assert_eq!(unpadded.format_byte(&5), "101");
+        assert_eq!(unpadded.format_byte(&200), "11001000");
+        assert_eq!(
+            unpadded.with_padded(true).format_buffer(&buffer),
+            padded.format_buffer(&buffer)
+        );
+    }
+
+    #[test]
+    fn test_c_escape_formatting() {
+        let c_escape = CEscapeFormatter::new();
+
+        assert_eq!(
+            c_escape.format_buffer(b"\x01\x02abc\n"),
+            String::from(r#""\x01\x02abc\n""#)
+        );
+        assert_eq!(
+            c_escape.format_buffer(b"say \"hi\"\t\\\r\x00\x7f\xff~"),
+            String::from(r#""say \"hi\"\t\\\r\x00\x7f\xff~""#)
+        );
+        assert_eq!(c_escape.format_byte(&0x1b), String::from(r"\x1b"));
+        assert_eq!(c_escape.format_buffer(&[]), String::from(r#""""#));
+
+        // Boxed formatter must use overridden buffer formatting.
+        let boxed: Box<dyn BufferFormatter> = Box::new(CEscapeFormatter::new());
+        assert_eq!(boxed.format_buffer(b"ok\n"), String::from(r#""ok\n""#));
+    }
+
+    #[test]
+    fn test_directional_formatting() {
+        let directional = DirectionalFormatter::new(
+            FnFormatter::new(Some(""), |byte| char::from(*byte).to_string()),
+            HexadecimalFormatter::new(Some(" "), HexCase::Upper),
+        );
+
+        assert_eq!(
+            directional.format_buffer_with_kind(RecordKind::Read, b"OK"),
+            String::from("OK")
+        );
+        assert_eq!(
+            directional.format_buffer_with_kind(RecordKind::Write, b"OK"),
+            String::from("4F 4B")
+        );
+        assert_eq!(directional.format_buffer(b"OK"), String::from("4F 4B"));
+
+        // Boxed formatter must use overridden kind-aware buffer formatting.
+        let boxed: Box<dyn BufferFormatter> = Box::new(directional);
+        assert_eq!(
+            boxed.format_buffer_with_kind(RecordKind::Read, b"OK"),
+            String::from("OK")
+        );
+    }
+
+    #[test]
+    fn test_wrappers_pass_record_kind() {
+        let truncating = TruncatingFormatter::new(
+            DirectionalFormatter::new(
+                DecimalFormatter::new(Some(" ")),
+                HexadecimalFormatter::new_default(),
+            ),
+            Some(2),
+        );
+        assert_eq!(
+            truncating.format_buffer_with_kind(RecordKind::Read, &[10, 11, 12]),
+            String::from("10 11 … (+1 more bytes)")
+        );
+        assert_eq!(
+            truncating.format_buffer_with_kind(RecordKind::Write, &[10, 11]),
+            String::from("0a:0b")
+        );
+
+        let grouping = GroupingFormatter::new(
+            DirectionalFormatter::new(
+                DecimalFormatter::new(Some(" ")),
+                HexadecimalFormatter::new(Some(""), HexCase::Lower),
+            ),
+            2,
+            " | ",
+        );
+        assert_eq!(
+            grouping.format_buffer_with_kind(RecordKind::Read, &[10, 11, 12]),
+            String::from("10 11 | 12")
+        );
+        assert_eq!(
+            grouping.format_buffer_with_kind(RecordKind::Write, &[10, 11, 12]),
+            String::from("0a0b | 0c")
+        );
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_base64_formatting() {
+        let standard = Base64Formatter::new(Base64Alphabet::Standard);
+        let url_safe = Base64Formatter::new(Base64Alphabet::UrlSafe);
+
+        assert_eq!(
+            standard.format_buffer(FORMATTING_TEST_VALUES),
+            String::from("CgsMDQ4PEBES")
+        );
+        assert_eq!(standard.format_buffer(&[0xfb, 0xff]), String::from("+/8="));
+        assert_eq!(url_safe.format_buffer(&[0xfb, 0xff]), String::from("-_8="));
+        assert_eq!(standard.format_byte(&0xff), String::from("/w=="));
+        assert_eq!(standard.format_buffer(&[]), String::new());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_base64_round_trip() {
+        let buffer = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+
+        let standard = Base64Formatter::new(Base64Alphabet::Standard);
+        assert_eq!(
+            general_purpose::STANDARD
+                .decode(standard.format_buffer(&buffer))
+                .unwrap(),
+            buffer
+        );
+
+        let url_safe = Base64Formatter::new(Base64Alphabet::UrlSafe);
+        assert_eq!(
+            general_purpose::URL_SAFE
+                .decode(url_safe.format_buffer(&buffer))
+                .unwrap(),
+            buffer
+        );
+    }
+
+    #[test]
+    fn test_truncating_formatting() {
+        let truncating = TruncatingFormatter::new(HexadecimalFormatter::new_default(), Some(4));
+
+        // Shorter than the cap.
+        assert_eq!(
+            truncating.format_buffer(&FORMATTING_TEST_VALUES[..3]),
+            String::from("0a:0b:0c")
+        );
+        // Equal to the cap.
+        assert_eq!(
+            truncating.format_buffer(&FORMATTING_TEST_VALUES[..4]),
+            String::from("0a:0b:0c:0d")
+        );
+        // Longer than the cap.
+        assert_eq!(
+            truncating.format_buffer(FORMATTING_TEST_VALUES),
+            String::from("0a:0b:0c:0d … (+5 more bytes)")
+        );
+        assert_eq!(
+            truncating.format_buffer(&[0; 65536]),
+            String::from("00:00:00:00 … (+65532 more bytes)")
+        );
+
+        let zero = TruncatingFormatter::new(DecimalFormatter::new_default(), Some(0));
+        assert_eq!(zero.format_buffer(&[]), String::new());
+        assert_eq!(
+            zero.format_buffer(FORMATTING_TEST_VALUES),
+            String::from("… (+9 more bytes)")
+        );
+
+        let unlimited = TruncatingFormatter::new(DecimalFormatter::new_default(), None);
+        assert_eq!(
+            unlimited.format_buffer(FORMATTING_TEST_VALUES),
+            String::from("10:11:12:13:14:15:16:17:18")
+        );
+    }
+
+    #[test]
+    fn test_grouping_formatting() {
+        let words = GroupingFormatter::new(
+            HexadecimalFormatter::new(Some(""), HexCase::Lower),
+            4,
+            " | ",
+        );
+        assert_eq!(
+            words.format_buffer(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]),
+            String::from("01020304 | 05060708")
+        );
+
+        let pairs = GroupingFormatter::new(HexadecimalFormatter::new_default(), 2, " ");
+        assert_eq!(
+            pairs.format_buffer(FORMATTING_TEST_VALUES),
+            String::from("0a:0b 0c:0d 0e:0f 10:11 12")
+        );
+        assert_eq!(
+            pairs.format_buffer(&FORMATTING_TEST_VALUES[..4]),
+            String::from("0a:0b 0c:0d")
+        );
+        assert_eq!(pairs.format_buffer(&[]), String::new());
+
+        let disabled = GroupingFormatter::new(DecimalFormatter::new_default(), 0, " | ");
+        assert_eq!(
+            disabled.format_buffer(FORMATTING_TEST_VALUES),
+            DecimalFormatter::new_default().format_buffer(FORMATTING_TEST_VALUES)
+        );
+    }
+
+    #[test]
+    fn test_prefix_split_formatting() {
+        let frame = PrefixSplitFormatter::new(
+            HexadecimalFormatter::new_default(),
+            HexadecimalFormatter::new_default(),
+            2,
+            " || ",
+        );
+        assert_eq!(
+            frame.format_buffer(&[0x00, 0x03, 0x61, 0x62, 0x63]),
+            String::from("00:03 || 61:62:63")
+        );
+        // Buffers which are not longer than prefix are formatted entirely as prefix.
+        assert_eq!(frame.format_buffer(&[0x00, 0x03]), String::from("00:03"));
+        assert_eq!(frame.format_buffer(&[0x00]), String::from("00"));
+        assert_eq!(frame.format_buffer(&[]), String::new());
+
+        // Prefix and payload can have different style and separator.
+        let styled = PrefixSplitFormatter::new(
+            DecimalFormatter::new(Some("-")),
+            HexadecimalFormatter::new(Some(" "), HexCase::Upper),
+            2,
+            " | ",
+        );
+        assert_eq!(
+            styled.format_buffer(&[0x01, 0x2c, 0xab, 0xcd]),
+            String::from("1-44 | AB CD")
+        );
+        assert_eq!(styled.format_buffer(&[0x01, 0x2c]), String::from("1-44"));
+        assert_eq!(styled.get_separator(), " ");
+        assert_eq!(styled.format_byte(&0xab), "AB");
+
+        let directional = PrefixSplitFormatter::new(
+            DecimalFormatter::new_default(),
+            DirectionalFormatter::new(
+                DecimalFormatter::new(Some(" ")),
+                HexadecimalFormatter::new(Some(""), HexCase::Lower),
+            ),
+            1,
+            " | ",
+        );
+        assert_eq!(
+            directional.format_buffer_with_kind(RecordKind::Read, &[3, 10, 11, 12]),
+            String::from("3 | 10 11 12")
+        );
+        assert_eq!(
+            directional.format_buffer_with_kind(RecordKind::Write, &[3, 10, 11, 12]),
+            String::from("3 | 0a0b0c")
+        );
+        assert_eq!(
+            directional.format_buffer_with_kind(RecordKind::Write, &[3]),
+            String::from("3")
+        );
+
+        // Empty prefix formats the whole buffer as payload, without delimiter.
+        let without_prefix = PrefixSplitFormatter::new(
+            HexadecimalFormatter::new_default(),
+            DecimalFormatter::new_default(),
+            0,
+            " || ",
+        );
+        assert_eq!(
+            without_prefix.format_buffer(FORMATTING_TEST_VALUES),
+            DecimalFormatter::new_default().format_buffer(FORMATTING_TEST_VALUES)
+        );
+        assert_eq!(
+            without_prefix.format_buffer_with_kind(RecordKind::Read, &[1, 2]),
+            String::from("1:2")
+        );
+        assert_eq!(without_prefix.format_buffer(&[]), String::new());
+    }
+
+    #[test]
+    fn test_parse_formatter() {
+        let buffer: &[u8] = &[0x0a, 0xff, 0x22];
+        let cases: Vec<(&str, Box<dyn BufferFormatter>)> = vec![
+            (
+                "hex-lower",
+                Box::new(HexadecimalFormatter::new(Some(" "), HexCase::Lower)),
+            ),
+            (
+                "hex-upper",
+                Box::new(HexadecimalFormatter::new(Some(" "), HexCase::Upper)),
+            ),
+            ("decimal", Box::new(DecimalFormatter::new(Some(" ")))),
+            ("octal", Box::new(OctalFormatter::new(Some(" ")))),
+            ("binary", Box::new(BinaryFormatter::new(Some(" ")))),
+            ("hexdump", Box::new(HexdumpFormatter::new())),
+            ("hex-ascii", Box::new(HexAsciiFormatter::new())),
+            ("c-escape", Box::new(CEscapeFormatter::new())),
+            #[cfg(feature = "base64")]
+            (
+                "base64",
+                Box::new(Base64Formatter::new(Base64Alphabet::Standard)),
+            ),
+            #[cfg(feature = "base64")]
+            (
+                "base64-url",
+                Box::new(Base64Formatter::new(Base64Alphabet::UrlSafe)),
+            ),
+        ];
+        for (name, expected) in cases {
+            let parsed = parse_formatter(name, Some(" ")).unwrap();
+            assert_eq!(parsed.format_buffer(buffer), expected.format_buffer(buffer));
+            assert_eq!(parsed.get_separator(), expected.get_separator());
+        }
+
+        assert_eq!(
+            parse_formatter(" HEX-Upper ", None)
+                .unwrap()
+                .format_buffer(buffer),
+            String::from("0A:FF:22")
+        );
+        assert_eq!(
+            parse_formatter("decimal", None)
+                .unwrap()
+                .format_buffer(buffer),
+            String::from("10:255:34")
+        );
+        assert!(parse_formatter("hex", None).is_none());
+        #[cfg(not(feature = "base64"))]
+        assert!(parse_formatter("base64", None).is_none());
+        assert!(parse_formatter("", Some(" ")).is_none());
+    }
+
+    #[test]
+    fn test_fn_formatting() {
+        let formatter = FnFormatter::new(Some(" "), |byte| {
+            if byte.is_ascii_control() {
+                format!("0x{byte:02X} /* control */")
+            } else {
+                format!("0x{byte:02X}")
+            }
+        });
+
+        assert_eq!(
+            formatter.format_buffer(b"ab\n"),
+            String::from("0x61 0x62 0x0A /* control */")
+        );
+        assert_eq!(formatter.format_buffer(&[]), String::new());
+
+        let default_separator = FnFormatter::new(None, |byte| format!("{}", byte + 1));
+        assert_eq!(
+            default_separator.format_buffer(&[1, 2, 3]),
+            String::from("2:3:4")
+        );
+    }
+
     fn assert_unpin<T: Unpin>() {}
 
+    #[test]
+    fn test_display_buffer() {
+        use std::fmt::Write;
+
+        let hexadecimal = HexadecimalFormatter::new_default();
+        let hexdump = HexdumpFormatter::new();
+
+        let mut out = String::new();
+        write!(out, "[{}]", hexadecimal.display(FORMATTING_TEST_VALUES)).unwrap();
+        assert_eq!(
+            out,
+            format!("[{}]", hexadecimal.format_buffer(FORMATTING_TEST_VALUES))
+        );
+
+        out.clear();
+        write!(out, "{}", hexdump.display(FORMATTING_TEST_VALUES)).unwrap();
+        assert_eq!(out, hexdump.format_buffer(FORMATTING_TEST_VALUES));
+
+        let boxed: Box<dyn BufferFormatter> = Box::new(DecimalFormatter::new(Some(" ")));
+        assert_eq!(
+            DisplayBuffer::new(&boxed, &[1, 2, 3]).to_string(),
+            String::from("1 2 3")
+        );
+        assert_eq!(hexadecimal.display(&[]).to_string(), String::new());
+
+        let formatters: Vec<Box<dyn BufferFormatter + Send>> = vec![
+            Box::new(HexadecimalFormatter::new_default()),
+            Box::new(BinaryFormatter::new_default().with_padded(false)),
+            Box::new(HexdumpFormatter::new()),
+            Box::new(HexAsciiFormatter::new()),
+            Box::new(CEscapeFormatter::new()),
+            #[cfg(feature = "base64")]
+            Box::new(Base64Formatter::new_default()),
+            Box::new(TruncatingFormatter::new(
+                DecimalFormatter::new_default(),
+                Some(3),
+            )),
+            Box::new(GroupingFormatter::new(
+                HexadecimalFormatter::new(Some(""), HexCase::Upper),
+                4,
+                " ",
+            )),
+            Box::new(PrefixSplitFormatter::new(
+                DecimalFormatter::new_default(),
+                GroupingFormatter::new(HexadecimalFormatter::new_default(), 2, " "),
+                2,
+                " || ",
+            )),
+            Box::new(DirectionalFormatter::new(
+                DecimalFormatter::new_default(),
+                OctalFormatter::new_default(),
+            )),
+            Box::new(FnFormatter::new(None, |byte| format!("{byte}"))),
+        ];
+        for formatter in formatters {
+            for buffer in [FORMATTING_TEST_VALUES, &[0x61, 0x0a], &[]] {
+                assert_eq!(
+                    formatter.display(buffer).to_string(),
+                    formatter.format_buffer(buffer)
+                );
+            }
+            let boxed: Box<dyn BufferFormatter> = Box::new(formatter);
+            assert_eq!(
+                boxed.display(FORMATTING_TEST_VALUES).to_string(),
+                boxed.format_buffer(FORMATTING_TEST_VALUES)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_buffer_into() {
+        struct ByteOnlyFormatter;
+
+        impl BufferFormatter for ByteOnlyFormatter {
+            fn get_separator(&self) -> &str {
+                "-"
+            }
+
+            fn format_byte(&self, byte: &u8) -> String {
+                format!("<{byte}>")
+            }
+        }
+
+        let formatters: Vec<Box<dyn BufferFormatter>> = vec![
+            Box::new(HexadecimalFormatter::new_default()),
+            Box::new(DecimalFormatter::new(Some(" "))),
+            Box::new(OctalFormatter::new_default()),
+            Box::new(BinaryFormatter::new_default()),
+            Box::new(HexdumpFormatter::new()),
+            Box::new(HexAsciiFormatter::new()),
+            Box::new(CEscapeFormatter::new()),
+            #[cfg(feature = "base64")]
+            Box::new(Base64Formatter::new_default()),
+            Box::new(TruncatingFormatter::new(
+                DecimalFormatter::new_default(),
+                Some(3),
+            )),
+            Box::new(GroupingFormatter::new(
+                HexadecimalFormatter::new(Some(""), HexCase::Upper),
+                4,
+                " ",
+            )),
+            Box::new(PrefixSplitFormatter::new(
+                DecimalFormatter::new_default(),
+                HexadecimalFormatter::new_default(),
+                2,
+                " || ",
+            )),
+            Box::new(FnFormatter::new(None, |byte| format!("{byte}"))),
+            Box::new(ByteOnlyFormatter),
+        ];
+
+        // Formatted output is appended to existing content, so one buffer can be reused between calls.
+        let mut out = String::new();
+        for formatter in formatters.iter() {
+            out.clear();
+            out.push_str("prefix ");
+            formatter.format_buffer_into(FORMATTING_TEST_VALUES, &mut out);
+            assert_eq!(
+                out,
+                format!("prefix {}", formatter.format_buffer(FORMATTING_TEST_VALUES))
+            );
+
+            let mut byte = String::new();
+            formatter.write_byte(&0x2a, &mut byte);
+            assert_eq!(byte, formatter.format_byte(&0x2a));
+        }
+
+        assert_eq!(
+            ByteOnlyFormatter.format_buffer(&[1, 2, 3]),
+            String::from("<1>-<2>-<3>")
+        );
+    }
+
     #[test]
     fn test_unpin() {
         assert_unpin::<BinaryFormatter>();
@@ -434,6 +2810,17 @@ mod tests {
         assert_unpin::<LowercaseHexadecimalFormatter>();
         assert_unpin::<UppercaseHexadecimalFormatter>();
         assert_unpin::<OctalFormatter>();
+        assert_unpin::<HexadecimalFormatter>();
+        assert_unpin::<HexdumpFormatter>();
+        assert_unpin::<HexAsciiFormatter>();
+        assert_unpin::<CEscapeFormatter>();
+        #[cfg(feature = "base64")]
+        assert_unpin::<Base64Formatter>();
+        assert_unpin::<TruncatingFormatter<DecimalFormatter>>();
+        assert_unpin::<GroupingFormatter<DecimalFormatter>>();
+        assert_unpin::<PrefixSplitFormatter<DecimalFormatter, DecimalFormatter>>();
+        assert_unpin::<FnFormatter>();
+        assert_unpin::<DirectionalFormatter<DecimalFormatter, HexadecimalFormatter>>();
     }
 
     #[test]
@@ -443,9 +2830,37 @@ mod tests {
             Box::new(LowercaseHexadecimalFormatter::new(None));
         let uppercase_hexadecimal: Box<dyn BufferFormatter> =
             Box::new(UppercaseHexadecimalFormatter::new(None));
+        let hexadecimal: Box<dyn BufferFormatter> =
+            Box::new(HexadecimalFormatter::new(None, HexCase::Upper));
         let decimal: Box<dyn BufferFormatter> = Box::new(DecimalFormatter::new(None));
         let octal: Box<dyn BufferFormatter> = Box::new(OctalFormatter::new(None));
         let binary: Box<dyn BufferFormatter> = Box::new(BinaryFormatter::new(None));
+        let hexdump: Box<dyn BufferFormatter> = Box::new(HexdumpFormatter::new());
+        let hex_ascii: Box<dyn BufferFormatter> = Box::new(HexAsciiFormatter::new());
+        let c_escape: Box<dyn BufferFormatter> = Box::new(CEscapeFormatter::new());
+        #[cfg(feature = "base64")]
+        let base64: Box<dyn BufferFormatter> = Box::new(Base64Formatter::new_default());
+        let truncating: Box<dyn BufferFormatter> = Box::new(TruncatingFormatter::new(
+            DecimalFormatter::new_default(),
+            Some(4),
+        ));
+        let grouping: Box<dyn BufferFormatter> = Box::new(GroupingFormatter::new(
+            DecimalFormatter::new_default(),
+            4,
+            " ",
+        ));
+        let prefix_split: Box<dyn BufferFormatter> = Box::new(PrefixSplitFormatter::new(
+            DecimalFormatter::new_default(),
+            DecimalFormatter::new_default(),
+            2,
+            " || ",
+        ));
+        let function: Box<dyn BufferFormatter> =
+            Box::new(FnFormatter::new(None, |byte| format!("{byte}")));
+        let directional: Box<dyn BufferFormatter> = Box::new(DirectionalFormatter::new(
+            DecimalFormatter::new_default(),
+            HexadecimalFormatter::new_default(),
+        ));
 
         // Assert that trait object methods are dispatchable.
         _ = lowercase_hexadecimal.get_separator();
@@ -454,6 +2869,9 @@ mod tests {
         _ = uppercase_hexadecimal.get_separator();
         _ = uppercase_hexadecimal.format_buffer(b"qwertyuiop");
 
+        _ = hexadecimal.get_separator();
+        _ = hexadecimal.format_buffer(b"qwertyuiop");
+
         _ = decimal.get_separator();
         _ = decimal.format_buffer(b"qwertyuiop");
 
@@ -462,6 +2880,37 @@ mod tests {
 
         _ = binary.get_separator();
         _ = binary.format_buffer(b"qwertyuiop");
+
+        _ = hexdump.get_separator();
+        _ = hexdump.format_buffer(b"qwertyuiop");
+
+        _ = hex_ascii.get_separator();
+        _ = hex_ascii.format_buffer(b"qwertyuiop");
+
+        _ = c_escape.get_separator();
+        _ = c_escape.format_buffer(b"qwertyuiop");
+
+        #[cfg(feature = "base64")]
+        {
+            _ = base64.get_separator();
+            _ = base64.format_buffer(b"qwertyuiop");
+        }
+
+        _ = truncating.get_separator();
+        _ = truncating.format_buffer(b"qwertyuiop");
+
+        _ = grouping.get_separator();
+        _ = grouping.format_buffer(b"qwertyuiop");
+
+        _ = prefix_split.get_separator();
+        _ = prefix_split.format_buffer(b"qwertyuiop");
+
+        _ = function.get_separator();
+        _ = function.format_buffer(b"qwertyuiop");
+
+        _ = directional.get_separator();
+        _ = directional.format_buffer(b"qwertyuiop");
+        _ = directional.format_buffer_with_kind(RecordKind::Read, b"qwertyuiop");
     }
 
     fn assert_buffer_formatter<T: BufferFormatter>() {}
@@ -469,11 +2918,31 @@ mod tests {
     #[test]
     fn test_box() {
         assert_buffer_formatter::<Box<dyn BufferFormatter>>();
+        assert_buffer_formatter::<Box<dyn BufferFormatter + Send>>();
+        assert_buffer_formatter::<Box<dyn BufferFormatter + Sync>>();
+        assert_buffer_formatter::<Box<dyn BufferFormatter + Send + Sync>>();
         assert_buffer_formatter::<Box<LowercaseHexadecimalFormatter>>();
         assert_buffer_formatter::<Box<UppercaseHexadecimalFormatter>>();
+        assert_buffer_formatter::<Box<HexadecimalFormatter>>();
         assert_buffer_formatter::<Box<DecimalFormatter>>();
         assert_buffer_formatter::<Box<OctalFormatter>>();
         assert_buffer_formatter::<Box<BinaryFormatter>>();
+        assert_buffer_formatter::<Box<HexdumpFormatter>>();
+        assert_buffer_formatter::<Box<HexAsciiFormatter>>();
+        assert_buffer_formatter::<Box<CEscapeFormatter>>();
+        #[cfg(feature = "base64")]
+        assert_buffer_formatter::<Box<Base64Formatter>>();
+        assert_buffer_formatter::<Box<TruncatingFormatter<DecimalFormatter>>>();
+        assert_buffer_formatter::<TruncatingFormatter<Box<dyn BufferFormatter>>>();
+        assert_buffer_formatter::<Box<GroupingFormatter<DecimalFormatter>>>();
+        assert_buffer_formatter::<Box<PrefixSplitFormatter<DecimalFormatter, DecimalFormatter>>>();
+        assert_buffer_formatter::<GroupingFormatter<Box<dyn BufferFormatter>>>();
+        assert_buffer_formatter::<Box<FnFormatter>>();
+        assert_buffer_formatter::<Box<DirectionalFormatter<DecimalFormatter, HexadecimalFormatter>>>(
+        );
+        assert_buffer_formatter::<
+            DirectionalFormatter<Box<dyn BufferFormatter>, Box<dyn BufferFormatter>>,
+        >();
     }
 
     fn assert_send<T: Send>() {}
@@ -482,15 +2951,37 @@ mod tests {
     fn test_send() {
         assert_send::<LowercaseHexadecimalFormatter>();
         assert_send::<UppercaseHexadecimalFormatter>();
+        assert_send::<HexadecimalFormatter>();
         assert_send::<DecimalFormatter>();
         assert_send::<OctalFormatter>();
         assert_send::<BinaryFormatter>();
+        assert_send::<HexdumpFormatter>();
+        assert_send::<HexAsciiFormatter>();
+        assert_send::<CEscapeFormatter>();
+        #[cfg(feature = "base64")]
+        assert_send::<Base64Formatter>();
+        assert_send::<TruncatingFormatter<DecimalFormatter>>();
+        assert_send::<GroupingFormatter<DecimalFormatter>>();
+        assert_send::<PrefixSplitFormatter<DecimalFormatter, DecimalFormatter>>();
+        assert_send::<FnFormatter>();
+        assert_send::<DirectionalFormatter<DecimalFormatter, HexadecimalFormatter>>();
 
         assert_send::<Box<dyn BufferFormatter>>();
         assert_send::<Box<LowercaseHexadecimalFormatter>>();
         assert_send::<Box<UppercaseHexadecimalFormatter>>();
+        assert_send::<Box<HexadecimalFormatter>>();
         assert_send::<Box<DecimalFormatter>>();
         assert_send::<Box<OctalFormatter>>();
         assert_send::<Box<BinaryFormatter>>();
+        assert_send::<Box<HexdumpFormatter>>();
+        assert_send::<Box<HexAsciiFormatter>>();
+        assert_send::<Box<CEscapeFormatter>>();
+        #[cfg(feature = "base64")]
+        assert_send::<Box<Base64Formatter>>();
+        assert_send::<Box<TruncatingFormatter<DecimalFormatter>>>();
+        assert_send::<Box<GroupingFormatter<DecimalFormatter>>>();
+        assert_send::<Box<PrefixSplitFormatter<DecimalFormatter, DecimalFormatter>>>();
+        assert_send::<Box<FnFormatter>>();
+        assert_send::<Box<DirectionalFormatter<DecimalFormatter, HexadecimalFormatter>>>();
     }
 }
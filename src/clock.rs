@@ -0,0 +1,91 @@
+use chrono::DateTime;
+use chrono::Utc;
+use std::sync;
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Trait
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Trait for sources of log record creation timestamps.
+///
+/// By default [`LoggedStream`] stamps log records with current system time, another source, for example fixed
+/// time for deterministic tests, can be provided using [`LoggedStream::with_clock`] method.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`LoggedStream::with_clock`]: crate::LoggedStream::with_clock
+pub trait Clock: Send + Sync + 'static {
+    /// This method returns current time of the clock. It is called once for every log record emitted by
+    /// [`LoggedStream`], right before the log record is passed to filtering part.
+    ///
+    /// [`LoggedStream`]: crate::LoggedStream
+    fn now(&self) -> DateTime<Utc>;
+}
+
+impl Clock for Box<dyn Clock> {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+impl Clock for sync::Arc<dyn Clock> {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SystemClock
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`Clock`] trait returns current system time using [`Utc::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+impl Clock for Box<SystemClock> {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::clock::Clock;
+    use crate::clock::SystemClock;
+    use chrono::Utc;
+    use std::sync;
+
+    fn assert_clock<T: Clock>() {}
+
+    #[test]
+    fn test_clock() {
+        assert_clock::<SystemClock>();
+        assert_clock::<Box<SystemClock>>();
+        assert_clock::<Box<dyn Clock>>();
+        assert_clock::<sync::Arc<dyn Clock>>();
+    }
+
+    #[test]
+    fn test_system_clock() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+        let boxed: Box<dyn Clock> = Box::new(SystemClock);
+        let after = boxed.now();
+        assert!(before <= now);
+        assert!(now <= after);
+        assert!(after <= Utc::now());
+    }
+}
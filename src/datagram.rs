@@ -0,0 +1,493 @@
+use crate::buffer_formatter::BufferFormatter;
+use crate::clock::Clock;
+use crate::filter::RecordFilter;
+use crate::logger::Logger;
+use crate::record::Record;
+use crate::record::RecordKind;
+use std::fmt;
+use std::io;
+use std::net;
+use std::sync;
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LoggedDatagram
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This is a datagram analogue of [`LoggedStream`] which wraps UDP socket and logs every sent and received
+/// datagram as a separate log record.
+///
+/// It constructs from the same formatting, filtering and logging parts as [`LoggedStream`]. Sent datagram is
+/// logged as log record with [`Write`] kind and received one as log record with [`Read`] kind, message of both
+/// contains formatted datagram payload followed by peer address. Wrapping of [`net::UdpSocket`] is supported, as
+/// well as wrapping of `tokio::net::UdpSocket` (requires `net` feature). Label, clock, keeping of transferred
+/// bytes, emitting of log record with [`Drop`] kind and pausing of logging are configured the same way as for
+/// [`LoggedStream`].
+///
+/// [`Drop`]: RecordKind::Drop
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`Write`]: RecordKind::Write
+/// [`Read`]: RecordKind::Read
+pub struct LoggedDatagram<
+    S: 'static,
+    Formatter: 'static,
+    Filter: RecordFilter + 'static,
+    L: Logger + 'static,
+> {
+    inner_socket: S,
+    formatter: Formatter,
+    filter: Filter,
+    logger: L,
+    label: Option<String>,
+    clock: Option<sync::Arc<dyn Clock>>,
+    keep_raw: bool,
+    log_drop: bool,
+    enabled: bool,
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedDatagram<S, Formatter, Filter, L>
+{
+    /// Construct a new instance of [`LoggedDatagram`] using provided arguments. Log record with [`Open`] kind is
+    /// passed to the logging part right after construction.
+    ///
+    /// [`Open`]: RecordKind::Open
+    pub fn new(socket: S, formatter: Formatter, filter: Filter, logger: L) -> Self {
+        let mut logged_datagram = Self {
+            inner_socket: socket,
+            formatter,
+            filter,
+            logger,
+            label: None,
+            clock: None,
+            keep_raw: false,
+            log_drop: true,
+            enabled: true,
+        };
+        logged_datagram.log_filtered(Record::new(RecordKind::Open, String::from("Opened.")));
+        logged_datagram
+    }
+
+    /// Sets label which is attached to every log record emitted after the call, for example address of the peer.
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Enable or disable attaching of datagram payload to log records with [`Read`] and [`Write`] kinds, so it can
+    /// be accessed using [`Record::message_bytes`] method and inspected by filtering part, for example by
+    /// [`ByteValueFilter`]. It is disabled by default, since every transferred byte is copied into log record.
+    ///
+    /// [`Read`]: RecordKind::Read
+    /// [`Write`]: RecordKind::Write
+    /// [`ByteValueFilter`]: crate::ByteValueFilter
+    pub fn with_keep_raw(mut self, keep_raw: bool) -> Self {
+        self.keep_raw = keep_raw;
+        self
+    }
+
+    /// Enable or disable emitting of log record with [`Drop`] kind when [`LoggedDatagram`] is dropped. It is
+    /// enabled by default.
+    ///
+    /// [`Drop`]: RecordKind::Drop
+    pub fn with_log_drop(mut self, log_drop: bool) -> Self {
+        self.log_drop = log_drop;
+        self
+    }
+
+    /// Replaces source of creation timestamps of emitted log records, which is [`SystemClock`] by default. Note
+    /// that log record with [`Open`] kind is emitted during construction, so it is stamped with system time.
+    ///
+    /// [`SystemClock`]: crate::SystemClock
+    /// [`Open`]: RecordKind::Open
+    pub fn with_clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Some(sync::Arc::new(clock));
+        self
+    }
+
+    /// Pauses or resumes logging. While logging is paused, no log records are emitted at all, including log record
+    /// with [`Drop`] kind, and datagrams are not formatted. Logging is enabled by default.
+    ///
+    /// [`Drop`]: RecordKind::Drop
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Returns `true` if logging is not paused using [`set_enabled`] method.
+    ///
+    /// [`set_enabled`]: LoggedDatagram::set_enabled
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns label attached to emitted log records, if any.
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn log_filtered(&mut self, mut record: Record) {
+        if !self.enabled {
+            return;
+        }
+        record.stamp(self.label.as_deref(), self.clock.as_deref());
+        if self.filter.check(&record) {
+            self.logger.log(record);
+        }
+    }
+
+    fn log_unfiltered(&mut self, mut record: Record) {
+        if !self.enabled {
+            return;
+        }
+        record.stamp(self.label.as_deref(), self.clock.as_deref());
+        self.logger.log(record);
+    }
+
+    /// Returns a shared reference to the underlying socket.
+    #[inline]
+    pub fn get_ref(&self) -> &S {
+        &self.inner_socket
+    }
+
+    /// Returns a mutable reference to the underlying socket. Datagrams sent or received directly through it are
+    /// not logged.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner_socket
+    }
+}
+
+impl<
+        S: 'static,
+        Formatter: BufferFormatter + 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > LoggedDatagram<S, Formatter, Filter, L>
+{
+    /// Constructs log record with provided kind describing transferred datagram and its peer. Datagram payload is
+    /// attached to log record only in case if it was enabled using [`with_keep_raw`] method.
+    ///
+    /// [`with_keep_raw`]: LoggedDatagram::with_keep_raw
+    fn transfer_record(&self, kind: RecordKind, payload: &[u8], peer: String) -> Record {
+        let message = format!(
+            "{} ({peer})",
+            self.formatter.format_buffer_with_kind(kind, payload)
+        );
+        if self.keep_raw {
+            Record::with_raw(kind, message, payload.to_vec())
+        } else {
+            Record::with_byte_count(kind, message, payload.len())
+        }
+    }
+
+    fn log_sent(&mut self, result: &io::Result<usize>, buf: &[u8], target: net::SocketAddr) {
+        if !self.enabled {
+            return;
+        }
+        match result {
+            Ok(length) => {
+                let record = self.transfer_record(
+                    RecordKind::Write,
+                    &buf[0..*length],
+                    format!("to {target}"),
+                );
+                self.log_filtered(record);
+            }
+            Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock) => {}
+            Err(e) => self.log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during send to {target}: {e}"),
+                e,
+            )),
+        }
+    }
+
+    fn log_received(&mut self, result: &io::Result<(usize, net::SocketAddr)>, buf: &[u8]) {
+        if !self.enabled {
+            return;
+        }
+        match result {
+            Ok((length, source)) => {
+                let record = self.transfer_record(
+                    RecordKind::Read,
+                    &buf[0..*length],
+                    format!("from {source}"),
+                );
+                self.log_filtered(record);
+            }
+            Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock) => {}
+            Err(e) => self.log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during receive: {e}"),
+                e,
+            )),
+        }
+    }
+}
+
+impl<Formatter: BufferFormatter + 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedDatagram<net::UdpSocket, Formatter, Filter, L>
+{
+    /// Sends provided datagram to the specified address and logs it, see [`net::UdpSocket::send_to`].
+    pub fn send_to(&mut self, buf: &[u8], target: net::SocketAddr) -> io::Result<usize> {
+        let result = self.inner_socket.send_to(buf, target);
+        self.log_sent(&result, buf, target);
+        result
+    }
+
+    /// Receives a single datagram and logs it together with the source address, see
+    /// [`net::UdpSocket::recv_from`].
+    pub fn recv_from(&mut self, buf: &mut [u8]) -> io::Result<(usize, net::SocketAddr)> {
+        let result = self.inner_socket.recv_from(buf);
+        self.log_received(&result, buf);
+        result
+    }
+}
+
+#[cfg(feature = "net")]
+impl<Formatter: BufferFormatter + 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedDatagram<tokio::net::UdpSocket, Formatter, Filter, L>
+{
+    /// Sends provided datagram to the specified address and logs it, see [`tokio::net::UdpSocket::send_to`].
+    pub async fn send_to(&mut self, buf: &[u8], target: net::SocketAddr) -> io::Result<usize> {
+        let result = self.inner_socket.send_to(buf, target).await;
+        self.log_sent(&result, buf, target);
+        result
+    }
+
+    /// Receives a single datagram and logs it together with the source address, see
+    /// [`tokio::net::UdpSocket::recv_from`].
+    pub async fn recv_from(&mut self, buf: &mut [u8]) -> io::Result<(usize, net::SocketAddr)> {
+        let result = self.inner_socket.recv_from(buf).await;
+        self.log_received(&result, buf);
+        result
+    }
+}
+
+impl<
+        S: fmt::Debug + 'static,
+        Formatter: fmt::Debug + 'static,
+        Filter: RecordFilter + fmt::Debug + 'static,
+        L: Logger + fmt::Debug + 'static,
+    > fmt::Debug for LoggedDatagram<S, Formatter, Filter, L>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggedDatagram")
+            .field("inner_socket", &self.inner_socket)
+            .field("formatter", &self.formatter)
+            .field("filter", &self.filter)
+            .field("logger", &self.logger)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> Drop
+    for LoggedDatagram<S, Formatter, Filter, L>
+{
+    fn drop(&mut self) {
+        if !self.log_drop {
+            return;
+        }
+        let record = Record::new(RecordKind::Drop, String::from("Deallocated."));
+        self.log_filtered(record);
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::clock::Clock;
+    use crate::datagram::LoggedDatagram;
+    use crate::filter::ByteValueFilter;
+    use crate::filter::DefaultFilter;
+    use crate::logger::ChannelLogger;
+    use crate::logger::MemoryStorageLogger;
+    use crate::record::RecordKind;
+    use chrono::DateTime;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use std::net;
+
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn test_unpin() {
+        assert_unpin::<
+            LoggedDatagram<
+                net::UdpSocket,
+                HexadecimalFormatter,
+                DefaultFilter,
+                MemoryStorageLogger,
+            >,
+        >();
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_send() {
+        assert_send::<
+            LoggedDatagram<
+                net::UdpSocket,
+                HexadecimalFormatter,
+                DefaultFilter,
+                MemoryStorageLogger,
+            >,
+        >();
+    }
+
+    #[test]
+    fn test_udp_socket() {
+        let peer = net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let peer_address = peer.local_addr().unwrap();
+        let mut logged_datagram = LoggedDatagram::new(
+            net::UdpSocket::bind("127.0.0.1:0").unwrap(),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        let local_address = logged_datagram.get_ref().local_addr().unwrap();
+
+        assert_eq!(
+            logged_datagram
+                .send_to(&[0x01, 0x02], peer_address)
+                .unwrap(),
+            2
+        );
+        let mut buffer = [0u8; 16];
+        assert_eq!(peer.recv_from(&mut buffer).unwrap(), (2, local_address));
+
+        peer.send_to(&[0x03], local_address).unwrap();
+        assert_eq!(
+            logged_datagram.recv_from(&mut buffer).unwrap(),
+            (1, peer_address)
+        );
+
+        let records = logged_datagram.logger.get_log_records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_eq!(records[1].kind, RecordKind::Write);
+        assert_eq!(records[1].message, format!("01:02 (to {peer_address})"));
+        assert_eq!(records[1].byte_count, Some(2));
+        assert_eq!(records[2].kind, RecordKind::Read);
+        assert_eq!(records[2].message, format!("03 (from {peer_address})"));
+        assert_eq!(records[2].byte_count, Some(1));
+    }
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_settings() {
+        let peer = net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let peer_address = peer.local_addr().unwrap();
+        let time = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let mut logger = ChannelLogger::new();
+        let receiver = logger.take_receiver().unwrap();
+        let mut logged_datagram = LoggedDatagram::new(
+            net::UdpSocket::bind("127.0.0.1:0").unwrap(),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            logger,
+        )
+        .with_label(String::from("peer"))
+        .with_clock(FixedClock(time))
+        .with_log_drop(false);
+        assert_eq!(logged_datagram.label(), Some("peer"));
+
+        logged_datagram.send_to(&[0x01], peer_address).unwrap();
+        logged_datagram.set_enabled(false);
+        assert!(!logged_datagram.is_enabled());
+        logged_datagram.send_to(&[0x02], peer_address).unwrap();
+        logged_datagram.set_enabled(true);
+        logged_datagram.send_to(&[0x03], peer_address).unwrap();
+        drop(logged_datagram);
+
+        let records = receiver.iter().collect::<Vec<_>>();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [RecordKind::Open, RecordKind::Write, RecordKind::Write]
+        );
+        assert_eq!(records[0].label, None);
+        assert_eq!(records[1].message, format!("01 (to {peer_address})"));
+        assert_eq!(records[1].label.as_deref(), Some("peer"));
+        assert_eq!(records[1].time, time);
+        assert_eq!(records[2].message, format!("03 (to {peer_address})"));
+    }
+
+    #[test]
+    fn test_keep_raw() {
+        let peer = net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let peer_address = peer.local_addr().unwrap();
+        let mut logged_datagram = LoggedDatagram::new(
+            net::UdpSocket::bind("127.0.0.1:0").unwrap(),
+            HexadecimalFormatter::new_default(),
+            ByteValueFilter::new(&[0xff]),
+            MemoryStorageLogger::new(100),
+        )
+        .with_keep_raw(true);
+
+        logged_datagram
+            .send_to(&[0x01, 0x02], peer_address)
+            .unwrap();
+        logged_datagram
+            .send_to(&[0x03, 0xff], peer_address)
+            .unwrap();
+
+        let records = logged_datagram.logger.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[0].message, format!("03:ff (to {peer_address})"));
+        assert_eq!(records[0].raw, Some(vec![0x03, 0xff]));
+        assert_eq!(records[0].byte_count, Some(2));
+    }
+
+    #[cfg(feature = "net")]
+    #[tokio::test]
+    async fn test_tokio_udp_socket() {
+        let peer = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let peer_address = peer.local_addr().unwrap();
+        let mut logged_datagram = LoggedDatagram::new(
+            tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap(),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        let local_address = logged_datagram.get_ref().local_addr().unwrap();
+
+        logged_datagram
+            .send_to(&[0x0a, 0x0b, 0x0c], peer_address)
+            .await
+            .unwrap();
+        let mut buffer = [0u8; 16];
+        assert_eq!(
+            peer.recv_from(&mut buffer).await.unwrap(),
+            (3, local_address)
+        );
+
+        peer.send_to(&[0x0d], local_address).await.unwrap();
+        assert_eq!(
+            logged_datagram.recv_from(&mut buffer).await.unwrap(),
+            (1, peer_address)
+        );
+
+        let records = logged_datagram.logger.get_log_records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1].kind, RecordKind::Write);
+        assert_eq!(records[1].message, format!("0a:0b:0c (to {peer_address})"));
+        assert_eq!(records[2].kind, RecordKind::Read);
+        assert_eq!(records[2].message, format!("0d (from {peer_address})"));
+    }
+}
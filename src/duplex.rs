@@ -0,0 +1,216 @@
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use tokio::io as tokio_io;
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Duplex
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Duplex IO object which reads from one inner object and writes into another one.
+///
+/// It joins distinct reader and writer, for example separate halves of a transport which doesn't expose single
+/// duplex object, so they can be wrapped into [`LoggedStream`] and logged by the same formatting, filtering and
+/// logging parts, see [`LoggedDuplex`]. Read operations are delegated to the reader and write operations to the
+/// writer, both synchronous and asynchronous ones.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`LoggedDuplex`]: crate::LoggedDuplex
+#[derive(Debug)]
+pub struct Duplex<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl<R, W> Duplex<R, W> {
+    /// Construct a new instance of [`Duplex`] using provided reader and writer.
+    pub fn from_parts(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+
+    /// Consumes [`Duplex`] and returns inner reader and writer.
+    pub fn into_parts(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+
+    /// Returns shared references to inner reader and writer.
+    #[inline]
+    pub fn get_ref(&self) -> (&R, &W) {
+        (&self.reader, &self.writer)
+    }
+
+    /// Returns mutable references to inner reader and writer. Bytes transferred directly through them are not
+    /// logged by wrapping [`LoggedStream`].
+    ///
+    /// [`LoggedStream`]: crate::LoggedStream
+    #[inline]
+    pub fn get_mut(&mut self) -> (&mut R, &mut W) {
+        (&mut self.reader, &mut self.writer)
+    }
+}
+
+impl<R: io::Read, W> io::Read for Duplex<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+impl<R, W: io::Write> io::Write for Duplex<R, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<R: tokio_io::AsyncRead + Unpin, W: Unpin> tokio_io::AsyncRead for Duplex<R, W> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio_io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().reader).poll_read(cx, buf)
+    }
+}
+
+impl<R: Unpin, W: tokio_io::AsyncWrite + Unpin> tokio_io::AsyncWrite for Duplex<R, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        Pin::new(&mut self.get_mut().writer).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        Pin::new(&mut self.get_mut().writer).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        Pin::new(&mut self.get_mut().writer).poll_shutdown(cx)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::duplex::Duplex;
+    use crate::filter::DefaultFilter;
+    use crate::logger::MemoryStorageLogger;
+    use crate::record::RecordKind;
+    use crate::stream::LoggedDuplex;
+    use crate::stream::LoggedStream;
+    use std::io;
+    use std::io::Read;
+    use std::io::Write;
+
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn test_unpin() {
+        assert_unpin::<Duplex<tokio::io::DuplexStream, tokio::io::DuplexStream>>();
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_send() {
+        assert_send::<Duplex<tokio::io::DuplexStream, tokio::io::DuplexStream>>();
+    }
+
+    #[test]
+    fn test_duplex() {
+        let mut duplex =
+            Duplex::from_parts(io::Cursor::new(vec![0x01, 0x02, 0x03]), Vec::<u8>::new());
+
+        let mut read = [0u8; 2];
+        duplex.read_exact(&mut read).unwrap();
+        duplex.write_all(&read).unwrap();
+        assert_eq!(duplex.get_ref().0.position(), 2);
+        assert_eq!(duplex.get_ref().1, &vec![0x01, 0x02]);
+
+        let (reader, writer) = duplex.get_mut();
+        reader.set_position(0);
+        writer.clear();
+        duplex.read_exact(&mut read).unwrap();
+        assert_eq!(read, [0x01, 0x02]);
+
+        let (reader, writer) = duplex.into_parts();
+        assert_eq!(reader.position(), 2);
+        assert!(writer.is_empty());
+    }
+
+    #[test]
+    fn test_logged_duplex() {
+        let mut logged_duplex: LoggedDuplex<_, _, _, _, _> = LoggedStream::duplex(
+            io::Cursor::new(vec![0x01, 0x02]),
+            Vec::<u8>::new(),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+
+        let mut read = [0u8; 2];
+        logged_duplex.read_exact(&mut read).unwrap();
+        logged_duplex.write_all(&[0x03, 0x04, 0x05]).unwrap();
+
+        let records = logged_duplex.get_log_records();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [RecordKind::Open, RecordKind::Read, RecordKind::Write]
+        );
+        assert_eq!(records[1].message, String::from("01:02"));
+        assert_eq!(records[2].message, String::from("03:04:05"));
+
+        let (reader, writer) = logged_duplex.into_inner().into_parts();
+        assert_eq!(reader.position(), 2);
+        assert_eq!(writer, vec![0x03, 0x04, 0x05]);
+    }
+
+    #[tokio::test]
+    async fn test_logged_duplex_async() {
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+
+        let (reader, mut remote_writer) = tokio::io::duplex(64);
+        let (writer, mut remote_reader) = tokio::io::duplex(64);
+        let mut logged_duplex = LoggedStream::duplex(
+            reader,
+            writer,
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+
+        remote_writer.write_all(&[0x0a, 0x0b]).await.unwrap();
+        let mut read = [0u8; 2];
+        logged_duplex.read_exact(&mut read).await.unwrap();
+        logged_duplex.write_all(&[0x0c]).await.unwrap();
+        logged_duplex.shutdown().await.unwrap();
+        let mut written = Vec::new();
+        remote_reader.read_to_end(&mut written).await.unwrap();
+        assert_eq!(written, vec![0x0c]);
+
+        let records = logged_duplex.get_log_records();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                RecordKind::Open,
+                RecordKind::Read,
+                RecordKind::Write,
+                RecordKind::Shutdown
+            ]
+        );
+        assert_eq!(records[1].message, String::from("0a:0b"));
+        assert_eq!(records[2].message, String::from("0c"));
+    }
+}
@@ -1,6 +1,10 @@
 use crate::Record;
 use crate::RecordKind;
+use chrono::DateTime;
+use chrono::Utc;
 use itertools::Itertools;
+use std::sync;
+use std::time;
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Trait
@@ -19,14 +23,52 @@ pub trait RecordFilter: Send + 'static {
     ///
     /// [`LoggedStream`]: crate::LoggedStream
     fn check(&self, record: &Record) -> bool;
-}
 
-impl RecordFilter for Box<dyn RecordFilter> {
-    fn check(&self, record: &Record) -> bool {
-        (**self).check(record)
+    /// Combine this filter with provided one into [`AndFilter`], which accepts log records accepted by both filters.
+    fn and<F: RecordFilter>(self, other: F) -> AndFilter<Self, F>
+    where
+        Self: Sized,
+    {
+        AndFilter::new(self, other)
+    }
+
+    /// Combine this filter with provided one into [`OrFilter`], which accepts log records accepted by at least one
+    /// of filters.
+    fn or<F: RecordFilter>(self, other: F) -> OrFilter<Self, F>
+    where
+        Self: Sized,
+    {
+        OrFilter::new(self, other)
+    }
+
+    /// Wrap this filter into [`NotFilter`], which accepts log records rejected by this filter.
+    fn not(self) -> NotFilter<Self>
+    where
+        Self: Sized,
+    {
+        NotFilter::new(self)
     }
 }
 
+macro_rules! impl_record_filter_for_boxed {
+    ($($object:ty),+ $(,)?) => {
+        $(
+            impl RecordFilter for Box<$object> {
+                fn check(&self, record: &Record) -> bool {
+                    (**self).check(record)
+                }
+            }
+        )+
+    };
+}
+
+impl_record_filter_for_boxed!(
+    dyn RecordFilter,
+    dyn RecordFilter + Send,
+    dyn RecordFilter + Sync,
+    dyn RecordFilter + Send + Sync,
+);
+
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // DefaultFilter
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -59,26 +101,77 @@ impl RecordFilter for Box<DefaultFilter> {
 ///
 /// This implementation of the [`RecordFilter`] trait accepts an array of allowed log record kinds ([`RecordKind`]) during
 /// construction. Its [`check`] method returns `true` if the received log record kind is present in this array.
+/// Provided kinds can be treated as a deny-list instead using [`RecordKindFilterMode::Deny`] mode, in this case
+/// [`check`] method returns `true` if the received log record kind is absent in this array.
 ///
 /// [`check`]: RecordFilter::check
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RecordKindFilter {
-    allowed_kinds: Vec<RecordKind>,
+    kinds: Vec<RecordKind>,
+    mode: RecordKindFilterMode,
+}
+
+/// Mode of [`RecordKindFilter`] which defines how provided log record kinds are treated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RecordKindFilterMode {
+    /// Accept only log records with provided kinds.
+    #[default]
+    Allow,
+    /// Accept all log records except ones with provided kinds.
+    Deny,
 }
 
 impl RecordKindFilter {
     /// Construct a new instance of [`RecordKindFilter`] using provided array of allowed log record kinds ([`RecordKind`]).
     pub fn new(kinds: &'static [RecordKind]) -> Self {
         Self {
-            allowed_kinds: kinds.iter().copied().unique().collect(),
+            kinds: kinds.iter().copied().unique().collect(),
+            mode: RecordKindFilterMode::default(),
         }
     }
+
+    /// Construct a new instance of [`RecordKindFilter`] using provided vector of allowed log record kinds
+    /// ([`RecordKind`]). Useful when allowed kinds are known only at runtime, for example parsed from configuration.
+    pub fn from_vec(kinds: Vec<RecordKind>) -> Self {
+        Self {
+            kinds: kinds.into_iter().unique().collect(),
+            mode: RecordKindFilterMode::default(),
+        }
+    }
+
+    /// Set mode which defines how provided log record kinds are treated. It is [`RecordKindFilterMode::Allow`]
+    /// by default.
+    pub fn with_mode(mut self, mode: RecordKindFilterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Add provided log record kind to the listed ones. Does nothing if it is already listed.
+    pub fn add_kind(&mut self, kind: RecordKind) {
+        if !self.kinds.contains(&kind) {
+            self.kinds.push(kind);
+        }
+    }
+
+    /// Remove provided log record kind from the listed ones. Does nothing if it is not listed.
+    pub fn remove_kind(&mut self, kind: RecordKind) {
+        self.kinds.retain(|listed| *listed != kind);
+    }
+
+    /// Replace all listed log record kinds with provided ones. Duplicates are dropped, mode stays unchanged.
+    pub fn set_kinds<I: IntoIterator<Item = RecordKind>>(&mut self, kinds: I) {
+        self.kinds = kinds.into_iter().unique().collect();
+    }
 }
 
 impl RecordFilter for RecordKindFilter {
     #[inline]
     fn check(&self, record: &Record) -> bool {
-        self.allowed_kinds.contains(&record.kind)
+        let listed = self.kinds.contains(&record.kind);
+        match self.mode {
+            RecordKindFilterMode::Allow => listed,
+            RecordKindFilterMode::Deny => !listed,
+        }
     }
 }
 
@@ -88,17 +181,679 @@ impl RecordFilter for Box<RecordKindFilter> {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ContentFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records which message contains provided substring.
+///
+/// This implementation of the [`RecordFilter`] trait accepts a needle string during construction. Its [`check`]
+/// method returns `true` if the received log record message contains this needle. Note that log record message is
+/// already formatted by [`BufferFormatter`], so the needle should be provided in the same form, for example
+/// `47:45:54` to match HTTP `GET` method formatted by [`HexadecimalFormatter`] with default separator.
+///
+/// [`check`]: RecordFilter::check
+/// [`BufferFormatter`]: crate::BufferFormatter
+/// [`HexadecimalFormatter`]: crate::HexadecimalFormatter
+#[derive(Debug, Clone)]
+pub struct ContentFilter {
+    needle: String,
+    case_insensitive: bool,
+}
+
+impl ContentFilter {
+    /// Construct a new instance of [`ContentFilter`] using provided borrowed needle. Matching is case-sensitive.
+    pub fn new(needle: &str) -> Self {
+        Self::new_owned(needle.to_string())
+    }
+
+    /// Construct a new instance of [`ContentFilter`] using provided owned needle. Matching is case-sensitive.
+    pub fn new_owned(needle: String) -> Self {
+        Self {
+            needle,
+            case_insensitive: false,
+        }
+    }
+
+    /// Construct a new instance of [`ContentFilter`] using provided borrowed needle. Matching is case-insensitive.
+    pub fn new_case_insensitive(needle: &str) -> Self {
+        Self {
+            needle: needle.to_lowercase(),
+            case_insensitive: true,
+        }
+    }
+}
+
+impl RecordFilter for ContentFilter {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        if self.case_insensitive {
+            record.message.to_lowercase().contains(self.needle.as_str())
+        } else {
+            record.message.contains(self.needle.as_str())
+        }
+    }
+}
+
+impl RecordFilter for Box<ContentFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RegexFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records which message matches provided regular expression.
+///
+/// This implementation of the [`RecordFilter`] trait accepts a regular expression pattern during construction. Its
+/// [`check`] method returns `true` if the received log record message matches this pattern. It is available only
+/// when `regex` feature is enabled.
+///
+/// [`check`]: RecordFilter::check
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct RegexFilter {
+    regex: regex::Regex,
+}
+
+#[cfg(feature = "regex")]
+impl RegexFilter {
+    /// Construct a new instance of [`RegexFilter`] using provided regular expression pattern. Returns an [`Err`]
+    /// in case if provided pattern is invalid.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: regex::Regex::new(pattern)?,
+        })
+    }
+}
+
+#[cfg(feature = "regex")]
+impl RecordFilter for RegexFilter {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        self.regex.is_match(&record.message)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl RecordFilter for Box<RegexFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AndFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records accepted by both inner filters.
+///
+/// Its [`check`] method short-circuits, so the second inner filter is not called if the first one rejected
+/// the log record. It can be constructed using [`new`] or [`RecordFilter::and`] methods.
+///
+/// [`check`]: RecordFilter::check
+/// [`new`]: AndFilter::new
+#[derive(Debug, Clone)]
+pub struct AndFilter<A: RecordFilter, B: RecordFilter> {
+    first: A,
+    second: B,
+}
+
+impl<A: RecordFilter, B: RecordFilter> AndFilter<A, B> {
+    /// Construct a new instance of [`AndFilter`] using provided inner filters.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A: RecordFilter, B: RecordFilter> RecordFilter for AndFilter<A, B> {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        self.first.check(record) && self.second.check(record)
+    }
+}
+
+impl<A: RecordFilter, B: RecordFilter> RecordFilter for Box<AndFilter<A, B>> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// OrFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records accepted by at least one of inner filters.
+///
+/// Its [`check`] method short-circuits, so the second inner filter is not called if the first one accepted
+/// the log record. It can be constructed using [`new`] or [`RecordFilter::or`] methods.
+///
+/// [`check`]: RecordFilter::check
+/// [`new`]: OrFilter::new
+#[derive(Debug, Clone)]
+pub struct OrFilter<A: RecordFilter, B: RecordFilter> {
+    first: A,
+    second: B,
+}
+
+impl<A: RecordFilter, B: RecordFilter> OrFilter<A, B> {
+    /// Construct a new instance of [`OrFilter`] using provided inner filters.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A: RecordFilter, B: RecordFilter> RecordFilter for OrFilter<A, B> {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        self.first.check(record) || self.second.check(record)
+    }
+}
+
+impl<A: RecordFilter, B: RecordFilter> RecordFilter for Box<OrFilter<A, B>> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// NotFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records rejected by inner filter.
+///
+/// It can be constructed using [`new`] or [`RecordFilter::not`] methods.
+///
+/// [`new`]: NotFilter::new
+#[derive(Debug, Clone)]
+pub struct NotFilter<F: RecordFilter> {
+    inner: F,
+}
+
+impl<F: RecordFilter> NotFilter<F> {
+    /// Construct a new instance of [`NotFilter`] using provided inner filter.
+    pub fn new(inner: F) -> Self {
+        Self { inner }
+    }
+}
+
+impl<F: RecordFilter> RecordFilter for NotFilter<F> {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        !self.inner.check(record)
+    }
+}
+
+impl<F: RecordFilter> RecordFilter for Box<NotFilter<F>> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// CountingFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that counts log records accepted and rejected by inner filter.
+///
+/// This implementation of the [`RecordFilter`] trait delegates [`check`] method to inner filter and tallies its
+/// decisions, which can be retrieved using [`accepted`] and [`rejected`] methods. It is useful for tuning
+/// filters, since it shows how many log records were dropped.
+///
+/// [`check`]: RecordFilter::check
+/// [`accepted`]: CountingFilter::accepted
+/// [`rejected`]: CountingFilter::rejected
+#[derive(Debug)]
+pub struct CountingFilter<F: RecordFilter> {
+    inner: F,
+    accepted: sync::atomic::AtomicU64,
+    rejected: sync::atomic::AtomicU64,
+}
+
+impl<F: RecordFilter> CountingFilter<F> {
+    /// Construct a new instance of [`CountingFilter`] using provided inner filter. Both counters start at zero.
+    pub fn new(inner: F) -> Self {
+        Self {
+            inner,
+            accepted: sync::atomic::AtomicU64::new(0),
+            rejected: sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    /// Returns a shared reference to the inner filter.
+    #[inline]
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+
+    /// Returns the number of log records accepted by inner filter.
+    #[inline]
+    pub fn accepted(&self) -> u64 {
+        self.accepted.load(sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Returns the number of log records rejected by inner filter.
+    #[inline]
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(sync::atomic::Ordering::Relaxed)
+    }
+}
+
+impl<F: RecordFilter> RecordFilter for CountingFilter<F> {
+    fn check(&self, record: &Record) -> bool {
+        let accepted = self.inner.check(record);
+        let counter = if accepted {
+            &self.accepted
+        } else {
+            &self.rejected
+        };
+        counter.fetch_add(1, sync::atomic::Ordering::Relaxed);
+        accepted
+    }
+}
+
+impl<F: RecordFilter> RecordFilter for Box<CountingFilter<F>> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// HeadFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts only the first N log records and rejects all following ones.
+///
+/// It is useful to capture only the beginning of a connection, for example handshake, without tearing down the
+/// logging part manually. Every checked log record, including one with [`Open`] kind, consumes one slot of the
+/// limit. The number of remaining slots is kept in an atomic counter.
+///
+/// [`Open`]: crate::RecordKind::Open
+#[derive(Debug)]
+pub struct HeadFilter {
+    remaining: sync::atomic::AtomicU64,
+}
+
+impl HeadFilter {
+    /// Construct a new instance of [`HeadFilter`] which accepts provided number of first log records.
+    pub fn new(count: u64) -> Self {
+        Self {
+            remaining: sync::atomic::AtomicU64::new(count),
+        }
+    }
+
+    /// Returns the number of log records which are still going to be accepted.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.remaining.load(sync::atomic::Ordering::Relaxed)
+    }
+}
+
+impl RecordFilter for HeadFilter {
+    #[inline]
+    fn check(&self, _record: &Record) -> bool {
+        self.remaining
+            .fetch_update(
+                sync::atomic::Ordering::Relaxed,
+                sync::atomic::Ordering::Relaxed,
+                |remaining| remaining.checked_sub(1),
+            )
+            .is_ok()
+    }
+}
+
+impl RecordFilter for Box<HeadFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RateLimitFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts at most specified number of log records per time interval.
+///
+/// This implementation of the [`RecordFilter`] trait uses token bucket algorithm keyed on log record creation
+/// time. The bucket holds at most `max_records` tokens and is fully refilled during one `interval`. Each accepted
+/// log record consumes one token, and log records received while the bucket is empty are rejected.
+#[derive(Debug)]
+pub struct RateLimitFilter {
+    max_records: usize,
+    interval: time::Duration,
+    state: sync::Mutex<RateLimitState>,
+}
+
+#[derive(Debug)]
+struct RateLimitState {
+    tokens: f64,
+    last_refill: Option<DateTime<Utc>>,
+}
+
+impl RateLimitFilter {
+    /// Construct a new instance of [`RateLimitFilter`] using provided max number of log records per provided
+    /// time interval.
+    pub fn new(max_records: usize, interval: time::Duration) -> Self {
+        Self {
+            max_records,
+            interval,
+            state: sync::Mutex::new(RateLimitState {
+                tokens: max_records as f64,
+                last_refill: None,
+            }),
+        }
+    }
+}
+
+impl RecordFilter for RateLimitFilter {
+    fn check(&self, record: &Record) -> bool {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let max_tokens = self.max_records as f64;
+        match state.last_refill {
+            Some(last_refill) if record.time > last_refill => {
+                let elapsed = (record.time - last_refill).to_std().unwrap_or_default();
+                let refill = if self.interval.is_zero() {
+                    max_tokens
+                } else {
+                    elapsed.as_secs_f64() / self.interval.as_secs_f64() * max_tokens
+                };
+                state.tokens = (state.tokens + refill).min(max_tokens);
+                state.last_refill = Some(record.time);
+            }
+            Some(_) => {}
+            None => state.last_refill = Some(record.time),
+        }
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl RecordFilter for Box<RateLimitFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SizeFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records which payload size is within specified bounds.
+///
+/// This implementation of the [`RecordFilter`] trait accepts optional inclusive lower and upper bounds during
+/// construction. Its [`check`] method compares them with the number of transferred bytes stored in
+/// [`Record::byte_count`], and falls back to log record message length in case if it is [`None`].
+///
+/// [`check`]: RecordFilter::check
+#[derive(Debug, Clone)]
+pub struct SizeFilter {
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+impl SizeFilter {
+    /// Construct a new instance of [`SizeFilter`] using provided inclusive bounds. [`None`] bound is not checked.
+    pub fn new(min: Option<usize>, max: Option<usize>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl RecordFilter for SizeFilter {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        let size = record.byte_count.unwrap_or(record.message.len());
+        self.min.map_or(true, |min| size >= min) && self.max.map_or(true, |max| size <= max)
+    }
+}
+
+impl RecordFilter for Box<SizeFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ByteValueFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records which transferred bytes contain any of specified
+/// byte values, for example an opcode of the searched frame.
+///
+/// Its [`check`] method inspects raw transferred bytes stored in [`Record::raw`] rather than formatted message,
+/// so it works regardless of formatting part. Raw bytes are attached to log records only when it is enabled using
+/// [`LoggedStream::with_keep_raw`] method, log records without them are rejected.
+///
+/// [`check`]: RecordFilter::check
+/// [`LoggedStream::with_keep_raw`]: crate::LoggedStream::with_keep_raw
+#[derive(Debug, Clone)]
+pub struct ByteValueFilter {
+    values: [bool; 256],
+}
+
+impl ByteValueFilter {
+    /// Construct a new instance of [`ByteValueFilter`] using provided set of searched byte values.
+    pub fn new(values: &[u8]) -> Self {
+        let mut table = [false; 256];
+        for value in values {
+            table[usize::from(*value)] = true;
+        }
+        Self { values: table }
+    }
+}
+
+impl RecordFilter for ByteValueFilter {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        record
+            .raw
+            .as_deref()
+            .is_some_and(|raw| raw.iter().any(|byte| self.values[usize::from(*byte)]))
+    }
+}
+
+impl RecordFilter for Box<ByteValueFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TimeWindowFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts log records which were created within specified time window.
+///
+/// This implementation of the [`RecordFilter`] trait accepts optional inclusive start and end bounds during
+/// construction. Its [`check`] method returns `true` if the received log record time ([`Record::time`]) is within
+/// these bounds. [`None`] bound means that time window is unbounded on that side.
+///
+/// [`check`]: RecordFilter::check
+#[derive(Debug, Clone)]
+pub struct TimeWindowFilter {
+    start: Option<DateTime<Utc>>,
+    end: Option<DateTime<Utc>>,
+}
+
+impl TimeWindowFilter {
+    /// Construct a new instance of [`TimeWindowFilter`] using provided inclusive bounds.
+    pub fn new(start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl RecordFilter for TimeWindowFilter {
+    #[inline]
+    fn check(&self, record: &Record) -> bool {
+        self.start.map_or(true, |start| record.time >= start)
+            && self.end.map_or(true, |end| record.time <= end)
+    }
+}
+
+impl RecordFilter for Box<TimeWindowFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DedupFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that rejects consecutive duplicates of log records.
+///
+/// This implementation of the [`RecordFilter`] trait remembers kind and message of the last checked log record.
+/// Its [`check`] method returns `false` if the received log record has the same kind ([`Record::kind`]) and
+/// message ([`Record::message`]) as the previous one, so repeated records such as heartbeats are logged only
+/// once until something else happens.
+///
+/// [`check`]: RecordFilter::check
+#[derive(Debug, Default)]
+pub struct DedupFilter {
+    last: sync::Mutex<Option<(RecordKind, String)>>,
+}
+
+impl DedupFilter {
+    /// Construct a new instance of [`DedupFilter`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RecordFilter for DedupFilter {
+    fn check(&self, record: &Record) -> bool {
+        let mut last = match self.last.lock() {
+            Ok(last) => last,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        match last.as_ref() {
+            Some((kind, message)) if *kind == record.kind && *message == record.message => false,
+            _ => {
+                *last = Some((record.kind, record.message.clone()));
+                true
+            }
+        }
+    }
+}
+
+impl RecordFilter for Box<DedupFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ProbabilisticFilter
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of [`RecordFilter`] that accepts random fraction of log records.
+///
+/// This implementation of the [`RecordFilter`] trait accepts probability during construction. Its [`check`]
+/// method returns `true` with this probability, independently of received log record. Random numbers are
+/// produced by an inner pseudorandom generator, which is seeded from current time by default and can be seeded
+/// explicitly using [`with_seed`] method to get reproducible sequence of decisions.
+///
+/// [`check`]: RecordFilter::check
+/// [`with_seed`]: ProbabilisticFilter::with_seed
+#[derive(Debug)]
+pub struct ProbabilisticFilter {
+    probability: f64,
+    state: sync::Mutex<u64>,
+}
+
+impl ProbabilisticFilter {
+    /// Construct a new instance of [`ProbabilisticFilter`] using provided probability of log record acceptance.
+    /// Provided probability is clamped to range from `0.0` to `1.0`.
+    pub fn new(probability: f64) -> Self {
+        let seed = time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or_default();
+        Self {
+            probability: if probability.is_nan() {
+                0.0
+            } else {
+                probability.clamp(0.0, 1.0)
+            },
+            state: sync::Mutex::new(seed),
+        }
+    }
+
+    /// Set seed of the inner pseudorandom generator, so the same sequence of decisions is made every time.
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            state: sync::Mutex::new(seed),
+            ..self
+        }
+    }
+
+    /// Returns next pseudorandom number within range from `0.0` (inclusive) to `1.0` (exclusive) using SplitMix64
+    /// algorithm.
+    fn next_random(&self) -> f64 {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = *state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^= value >> 31;
+        (value >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl RecordFilter for ProbabilisticFilter {
+    fn check(&self, _record: &Record) -> bool {
+        self.next_random() < self.probability
+    }
+}
+
+impl RecordFilter for Box<ProbabilisticFilter> {
+    fn check(&self, record: &Record) -> bool {
+        (**self).check(record)
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
+    use crate::filter::AndFilter;
+    use crate::filter::ByteValueFilter;
+    use crate::filter::ContentFilter;
+    use crate::filter::CountingFilter;
+    use crate::filter::DedupFilter;
     use crate::filter::DefaultFilter;
+    use crate::filter::HeadFilter;
+    use crate::filter::NotFilter;
+    use crate::filter::OrFilter;
+    use crate::filter::ProbabilisticFilter;
+    use crate::filter::RateLimitFilter;
     use crate::filter::RecordFilter;
     use crate::filter::RecordKindFilter;
+    use crate::filter::RecordKindFilterMode;
+    use crate::filter::SizeFilter;
+    use crate::filter::TimeWindowFilter;
     use crate::record::Record;
     use crate::record::RecordKind;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use std::time::Duration;
 
     fn assert_unpin<T: Unpin>() {}
 
@@ -106,6 +861,18 @@ mod tests {
     fn test_unpin() {
         assert_unpin::<DefaultFilter>();
         assert_unpin::<RecordKindFilter>();
+        assert_unpin::<ContentFilter>();
+        assert_unpin::<AndFilter<DefaultFilter, ContentFilter>>();
+        assert_unpin::<OrFilter<DefaultFilter, ContentFilter>>();
+        assert_unpin::<NotFilter<DefaultFilter>>();
+        assert_unpin::<CountingFilter<DefaultFilter>>();
+        assert_unpin::<HeadFilter>();
+        assert_unpin::<RateLimitFilter>();
+        assert_unpin::<DedupFilter>();
+        assert_unpin::<ProbabilisticFilter>();
+        assert_unpin::<SizeFilter>();
+        assert_unpin::<ByteValueFilter>();
+        assert_unpin::<TimeWindowFilter>();
     }
 
     #[test]
@@ -123,6 +890,10 @@ mod tests {
             RecordKind::Shutdown,
             String::from("write shutdown request")
         )));
+        assert!(DefaultFilter.check(&Record::new(
+            RecordKind::Flush,
+            String::from("flush request")
+        )));
     }
 
     #[test]
@@ -141,6 +912,432 @@ mod tests {
             RecordKind::Shutdown,
             String::from("write shutdown request")
         )));
+        assert!(!filter.check(&Record::new(
+            RecordKind::Flush,
+            String::from("flush request")
+        )));
+
+        let filter = RecordKindFilter::new(&[RecordKind::Flush]);
+        assert!(filter.check(&Record::new(
+            RecordKind::Flush,
+            String::from("flush request")
+        )));
+        assert!(!filter.check(&Record::new(
+            RecordKind::Write,
+            String::from("01:02:03:04:05:06")
+        )));
+    }
+
+    #[test]
+    fn test_record_kind_filter_deny_mode() {
+        let filter = RecordKindFilter::new(&[RecordKind::Drop, RecordKind::Shutdown])
+            .with_mode(RecordKindFilterMode::Deny);
+        assert!(!filter.check(&Record::new(RecordKind::Drop, String::from("deallocated"))));
+        assert!(!filter.check(&Record::new(
+            RecordKind::Shutdown,
+            String::from("write shutdown request")
+        )));
+        assert!(filter.check(&Record::new(
+            RecordKind::Read,
+            String::from("01:02:03:04:05:06")
+        )));
+        assert!(filter.check(&Record::new(
+            RecordKind::Write,
+            String::from("01:02:03:04:05:06")
+        )));
+        assert!(filter.check(&Record::new(
+            RecordKind::Flush,
+            String::from("flush request")
+        )));
+
+        // Empty deny-list accepts everything.
+        assert!(RecordKindFilter::from_vec(Vec::new())
+            .with_mode(RecordKindFilterMode::Deny)
+            .check(&Record::new(RecordKind::Error, String::from("error"))));
+    }
+
+    #[test]
+    fn test_record_kind_filter_from_vec() {
+        let kinds: Vec<RecordKind> = vec![RecordKind::Read, RecordKind::Error, RecordKind::Read];
+        let dynamic = RecordKindFilter::from_vec(kinds);
+        let fixed = RecordKindFilter::new(&[RecordKind::Read, RecordKind::Error]);
+        assert_eq!(dynamic.kinds, vec![RecordKind::Read, RecordKind::Error]);
+
+        for kind in [
+            RecordKind::Open,
+            RecordKind::Connect,
+            RecordKind::Read,
+            RecordKind::Eof,
+            RecordKind::Write,
+            RecordKind::Error,
+            RecordKind::Shutdown,
+            RecordKind::Flush,
+            RecordKind::Seek,
+            RecordKind::Pending,
+            RecordKind::Drop,
+        ] {
+            let record = Record::new(kind, String::from("01:02:03:04:05:06"));
+            assert_eq!(dynamic.check(&record), fixed.check(&record));
+        }
+        assert!(!RecordKindFilter::from_vec(Vec::new()).check(&Record::new(
+            RecordKind::Read,
+            String::from("01:02:03:04:05:06")
+        )));
+    }
+
+    #[test]
+    fn test_record_kind_filter_mutation() {
+        let read = Record::new(RecordKind::Read, String::from("01:02:03"));
+        let write = Record::new(RecordKind::Write, String::from("04:05:06"));
+
+        let mut filter = RecordKindFilter::new(&[RecordKind::Read]);
+        assert!(filter.check(&read));
+        assert!(!filter.check(&write));
+
+        filter.add_kind(RecordKind::Write);
+        filter.add_kind(RecordKind::Write);
+        assert_eq!(filter.kinds, vec![RecordKind::Read, RecordKind::Write]);
+        assert!(filter.check(&write));
+
+        filter.remove_kind(RecordKind::Read);
+        assert!(!filter.check(&read));
+        assert!(filter.check(&write));
+
+        filter.set_kinds([RecordKind::Error, RecordKind::Read, RecordKind::Error]);
+        assert_eq!(filter.kinds, vec![RecordKind::Error, RecordKind::Read]);
+        assert!(filter.check(&read));
+        assert!(!filter.check(&write));
+
+        let mut deny = RecordKindFilter::new(&[]).with_mode(RecordKindFilterMode::Deny);
+        deny.add_kind(RecordKind::Read);
+        assert!(!deny.check(&read));
+        assert!(deny.check(&write));
+    }
+
+    #[test]
+    fn test_content_filter() {
+        let filter = ContentFilter::new("47:45:54");
+        assert!(filter.check(&Record::new(
+            RecordKind::Read,
+            String::from("47:45:54:20:2f")
+        )));
+        assert!(filter.check(&Record::new(
+            RecordKind::Write,
+            String::from("0a:47:45:54:20")
+        )));
+        assert!(!filter.check(&Record::new(
+            RecordKind::Read,
+            String::from("50:4f:53:54:20")
+        )));
+        assert!(!filter.check(&Record::new(RecordKind::Drop, String::from("deallocated"))));
+
+        let filter = ContentFilter::new("0A:0B");
+        assert!(filter.check(&Record::new(RecordKind::Read, String::from("0A:0B:0C"))));
+        assert!(!filter.check(&Record::new(RecordKind::Read, String::from("0a:0b:0c"))));
+
+        let filter = ContentFilter::new_case_insensitive("0A:0B");
+        assert!(filter.check(&Record::new(RecordKind::Read, String::from("0A:0B:0C"))));
+        assert!(filter.check(&Record::new(RecordKind::Read, String::from("0a:0b:0c"))));
+        assert!(!filter.check(&Record::new(RecordKind::Read, String::from("0c:0d:0e"))));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_filter() {
+        use crate::filter::RegexFilter;
+
+        let filter = RegexFilter::new("^47:45:54(:|$)").unwrap();
+        assert!(filter.check(&Record::new(
+            RecordKind::Read,
+            String::from("47:45:54:20:2f")
+        )));
+        assert!(filter.check(&Record::new(RecordKind::Read, String::from("47:45:54"))));
+        assert!(!filter.check(&Record::new(
+            RecordKind::Read,
+            String::from("0a:47:45:54:20")
+        )));
+        assert!(!filter.check(&Record::new(RecordKind::Drop, String::from("deallocated"))));
+
+        assert!(RegexFilter::new("(unclosed").is_err());
+
+        assert_record_filter::<Box<RegexFilter>>();
+        assert_send::<RegexFilter>();
+    }
+
+    #[test]
+    fn test_combinator_filters() {
+        let read = Record::new(RecordKind::Read, String::from("47:45:54"));
+        let write = Record::new(RecordKind::Write, String::from("47:45:54"));
+        let other_read = Record::new(RecordKind::Read, String::from("50:4f:53"));
+
+        let and = RecordKindFilter::new(&[RecordKind::Read]).and(ContentFilter::new("47:45:54"));
+        assert!(and.check(&read));
+        assert!(!and.check(&write));
+        assert!(!and.check(&other_read));
+
+        let or = OrFilter::new(
+            RecordKindFilter::new(&[RecordKind::Write]),
+            ContentFilter::new("50:4f:53"),
+        );
+        assert!(!or.check(&read));
+        assert!(or.check(&write));
+        assert!(or.check(&other_read));
+
+        let not = RecordKindFilter::new(&[RecordKind::Read]).not();
+        assert!(!not.check(&read));
+        assert!(not.check(&write));
+
+        let nested = AndFilter::new(
+            NotFilter::new(ContentFilter::new("50:4f:53")),
+            RecordKindFilter::new(&[RecordKind::Read])
+                .or(RecordKindFilter::new(&[RecordKind::Write])),
+        );
+        assert!(nested.check(&read));
+        assert!(nested.check(&write));
+        assert!(!nested.check(&other_read));
+    }
+
+    #[test]
+    fn test_combinator_filters_short_circuit() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::atomic::Ordering;
+        use std::sync::Arc;
+
+        struct CountingFilter(Arc<AtomicUsize>);
+
+        impl RecordFilter for CountingFilter {
+            fn check(&self, _record: &Record) -> bool {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                true
+            }
+        }
+
+        let read = Record::new(RecordKind::Read, String::from("47:45:54"));
+        let write = Record::new(RecordKind::Write, String::from("47:45:54"));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let and = RecordKindFilter::new(&[RecordKind::Read]).and(CountingFilter(calls.clone()));
+        assert!(!and.check(&write));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(and.check(&read));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let or = ContentFilter::new("47:45:54").or(CountingFilter(calls.clone()));
+        assert!(or.check(&read));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(or.check(&Record::new(RecordKind::Read, String::from("00"))));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_dedup_filter() {
+        let filter = DedupFilter::new();
+        let heartbeat = Record::new(RecordKind::Write, String::from("01:02:03:04"));
+        let response = Record::new(RecordKind::Read, String::from("01:02:03:04"));
+
+        let accepted = [
+            heartbeat.clone(),
+            heartbeat.clone(),
+            heartbeat.clone(),
+            response.clone(),
+        ]
+        .iter()
+        .filter(|record| filter.check(record))
+        .cloned()
+        .collect::<Vec<_>>();
+        assert_eq!(accepted, [heartbeat.clone(), response]);
+
+        // Duplicate is accepted again after a different log record.
+        assert!(filter.check(&heartbeat));
+    }
+
+    #[test]
+    fn test_probabilistic_filter() {
+        let record = Record::new(RecordKind::Read, String::from("01:02:03"));
+
+        let filter = ProbabilisticFilter::new(0.5).with_seed(42);
+        let decisions = (0..12).map(|_| filter.check(&record)).collect::<Vec<_>>();
+        assert_eq!(
+            decisions,
+            [false, true, true, true, true, false, true, false, true, false, true, true]
+        );
+
+        // The same seed produces the same sequence.
+        let filter = ProbabilisticFilter::new(0.5).with_seed(42);
+        assert!(decisions
+            .iter()
+            .all(|decision| filter.check(&record) == *decision));
+
+        let always = ProbabilisticFilter::new(1.0);
+        assert!((0..100).all(|_| always.check(&record)));
+        let never = ProbabilisticFilter::new(-1.0);
+        assert!((0..100).all(|_| !never.check(&record)));
+        let never = ProbabilisticFilter::new(f64::NAN);
+        assert!((0..100).all(|_| !never.check(&record)));
+    }
+
+    #[test]
+    fn test_rate_limit_filter() {
+        let filter = RateLimitFilter::new(3, Duration::from_secs(60));
+        let mut record = Record::new(RecordKind::Read, String::from("01:02:03"));
+
+        let accepted = (0..10).filter(|_| filter.check(&record)).count();
+        assert_eq!(accepted, 3);
+
+        // A third of interval refills one token.
+        record.time += chrono::Duration::seconds(20);
+        assert!(filter.check(&record));
+        assert!(!filter.check(&record));
+
+        // Whole interval refills the bucket, but not over its capacity.
+        record.time += chrono::Duration::seconds(600);
+        let accepted = (0..10).filter(|_| filter.check(&record)).count();
+        assert_eq!(accepted, 3);
+
+        let filter = RateLimitFilter::new(0, Duration::from_secs(60));
+        assert!(!filter.check(&record));
+    }
+
+    #[test]
+    fn test_head_filter() {
+        let filter = HeadFilter::new(3);
+        assert_eq!(filter.remaining(), 3);
+
+        let decisions: Vec<bool> = (0..10u8)
+            .map(|index| filter.check(&Record::new(RecordKind::Read, format!("{index:02x}"))))
+            .collect();
+        assert_eq!(decisions.iter().filter(|accepted| **accepted).count(), 3);
+        assert_eq!(&decisions[..4], [true, true, true, false]);
+        assert_eq!(filter.remaining(), 0);
+
+        // Log records of any kind are rejected once the limit is exhausted.
+        assert!(!filter.check(&Record::new(RecordKind::Error, String::from("failure"))));
+        assert!(!HeadFilter::new(0).check(&Record::new(RecordKind::Open, String::from("Opened."))));
+    }
+
+    #[test]
+    fn test_size_filter() {
+        let filter = SizeFilter::new(Some(2), Some(4));
+        let record = |byte_count| {
+            Record::with_byte_count(RecordKind::Read, String::from("01:02:03:04:05"), byte_count)
+        };
+
+        // Below min.
+        assert!(!filter.check(&record(0)));
+        assert!(!filter.check(&record(1)));
+        // In range.
+        assert!(filter.check(&record(2)));
+        assert!(filter.check(&record(3)));
+        assert!(filter.check(&record(4)));
+        // Above max.
+        assert!(!filter.check(&record(5)));
+        assert!(!filter.check(&record(65536)));
+
+        // Unbounded sides.
+        assert!(SizeFilter::new(None, Some(4)).check(&record(0)));
+        assert!(SizeFilter::new(Some(2), None).check(&record(65536)));
+        assert!(SizeFilter::new(None, None).check(&record(65536)));
+
+        // Message length is used when byte count is absent.
+        assert!(filter.check(&Record::new(RecordKind::Drop, String::from("abc"))));
+        assert!(!filter.check(&Record::new(RecordKind::Drop, String::from("deallocated"))));
+    }
+
+    #[test]
+    fn test_byte_value_filter() {
+        use crate::buffer_formatter::HexadecimalFormatter;
+        use crate::logger::MemoryStorageLogger;
+        use crate::stream::LoggedStream;
+        use std::io;
+        use std::io::Write;
+
+        let filter = ByteValueFilter::new(&[0xff]);
+        let record = |raw: Vec<u8>| Record::with_raw(RecordKind::Read, String::new(), raw);
+        assert!(filter.check(&record(vec![0x01, 0xff, 0x02])));
+        assert!(filter.check(&record(vec![0xff])));
+        assert!(!filter.check(&record(vec![0x01, 0xfe, 0x02])));
+        assert!(!filter.check(&record(Vec::new())));
+        // Formatted message is not inspected.
+        assert!(!filter.check(&Record::with_byte_count(
+            RecordKind::Read,
+            String::from("ff"),
+            1
+        )));
+
+        let any = ByteValueFilter::new(&[0x00, 0x7f]);
+        assert!(any.check(&record(vec![0x7f])));
+        assert!(any.check(&record(vec![0x10, 0x00])));
+        assert!(!ByteValueFilter::new(&[]).check(&record(vec![0x00, 0xff])));
+
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            ByteValueFilter::new(&[0xff]),
+            MemoryStorageLogger::new(100),
+        )
+        .with_keep_raw(true);
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+        logged_stream.write_all(&[0x03, 0xff, 0x04]).unwrap();
+        logged_stream.write_all(&[0x05]).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, String::from("03:ff:04"));
+    }
+
+    #[test]
+    fn test_time_window_filter() {
+        let at = |hour| {
+            Record::with_time(
+                RecordKind::Read,
+                String::from("01:02:03"),
+                Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap(),
+            )
+        };
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        let filter = TimeWindowFilter::new(Some(start), Some(end));
+        // Before window.
+        assert!(!filter.check(&at(9)));
+        // Inside window, bounds are inclusive.
+        assert!(filter.check(&at(10)));
+        assert!(filter.check(&at(11)));
+        assert!(filter.check(&at(12)));
+        // After window.
+        assert!(!filter.check(&at(13)));
+
+        // Open-ended bounds.
+        assert!(TimeWindowFilter::new(None, Some(end)).check(&at(0)));
+        assert!(!TimeWindowFilter::new(None, Some(end)).check(&at(13)));
+        assert!(TimeWindowFilter::new(Some(start), None).check(&at(23)));
+        assert!(!TimeWindowFilter::new(Some(start), None).check(&at(9)));
+        assert!(TimeWindowFilter::new(None, None).check(&at(0)));
+    }
+
+    #[test]
+    fn test_counting_filter() {
+        let filter = CountingFilter::new(RecordKindFilter::new(&[RecordKind::Read]));
+        assert_eq!((filter.accepted(), filter.rejected()), (0, 0));
+
+        let kinds = [
+            RecordKind::Open,
+            RecordKind::Read,
+            RecordKind::Write,
+            RecordKind::Read,
+            RecordKind::Drop,
+        ];
+        let decisions: Vec<bool> = kinds
+            .iter()
+            .map(|kind| filter.check(&Record::new(*kind, String::from("01:02"))))
+            .collect();
+        assert_eq!(decisions, [false, true, false, true, false]);
+        assert_eq!(filter.accepted(), 2);
+        assert_eq!(filter.rejected(), 3);
+        assert!(filter
+            .inner()
+            .check(&Record::new(RecordKind::Read, String::new())));
+        assert_eq!(filter.accepted(), 2);
     }
 
     #[test]
@@ -148,12 +1345,14 @@ mod tests {
         // Assert traint object construct.
         let default: Box<dyn RecordFilter> = Box::<DefaultFilter>::default();
         let record_kind: Box<dyn RecordFilter> = Box::new(RecordKindFilter::new(&[]));
+        let content: Box<dyn RecordFilter> = Box::new(ContentFilter::new("test"));
 
         let record = Record::new(RecordKind::Open, String::from("test log record"));
 
         // Assert that trait object methods are dispatchable.
         _ = default.check(&record);
         _ = record_kind.check(&record);
+        _ = content.check(&record);
     }
 
     fn assert_record_filter<T: RecordFilter>() {}
@@ -161,8 +1360,24 @@ mod tests {
     #[test]
     fn test_box() {
         assert_record_filter::<Box<dyn RecordFilter>>();
+        assert_record_filter::<Box<dyn RecordFilter + Send>>();
+        assert_record_filter::<Box<dyn RecordFilter + Sync>>();
+        assert_record_filter::<Box<dyn RecordFilter + Send + Sync>>();
         assert_record_filter::<Box<RecordKindFilter>>();
         assert_record_filter::<Box<DefaultFilter>>();
+        assert_record_filter::<Box<ContentFilter>>();
+        assert_record_filter::<Box<AndFilter<DefaultFilter, ContentFilter>>>();
+        assert_record_filter::<Box<OrFilter<DefaultFilter, ContentFilter>>>();
+        assert_record_filter::<Box<NotFilter<DefaultFilter>>>();
+        assert_record_filter::<Box<CountingFilter<DefaultFilter>>>();
+        assert_record_filter::<Box<HeadFilter>>();
+        assert_record_filter::<Box<RateLimitFilter>>();
+        assert_record_filter::<Box<SizeFilter>>();
+        assert_record_filter::<Box<ByteValueFilter>>();
+        assert_record_filter::<Box<TimeWindowFilter>>();
+        assert_record_filter::<Box<DedupFilter>>();
+        assert_record_filter::<Box<ProbabilisticFilter>>();
+        assert_record_filter::<AndFilter<Box<dyn RecordFilter>, Box<dyn RecordFilter>>>();
     }
 
     fn assert_send<T: Send>() {}
@@ -171,9 +1386,22 @@ mod tests {
     fn test_send() {
         assert_send::<RecordKindFilter>();
         assert_send::<DefaultFilter>();
+        assert_send::<ContentFilter>();
+        assert_send::<AndFilter<DefaultFilter, ContentFilter>>();
+        assert_send::<OrFilter<DefaultFilter, ContentFilter>>();
+        assert_send::<NotFilter<DefaultFilter>>();
+        assert_send::<CountingFilter<DefaultFilter>>();
+        assert_send::<HeadFilter>();
+        assert_send::<RateLimitFilter>();
+        assert_send::<SizeFilter>();
+        assert_send::<ByteValueFilter>();
+        assert_send::<TimeWindowFilter>();
+        assert_send::<DedupFilter>();
+        assert_send::<ProbabilisticFilter>();
 
         assert_send::<Box<dyn RecordFilter>>();
         assert_send::<Box<RecordKindFilter>>();
         assert_send::<Box<DefaultFilter>>();
+        assert_send::<Box<ContentFilter>>();
     }
 }
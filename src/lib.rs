@@ -7,24 +7,57 @@
 //!
 //! -   Underlying IO object, which must implement [`Write`] and [`Read`] traits or their
 //!     asynchronous analogues from [`tokio`] library: [`AsyncRead`] and [`AsyncWrite`].
-//! -   Buffer formatting part, which must implement [`BufferFormatter`] trait provided by
-//!     this library. This part of [`LoggedStream`] is responsible for the form you will see the
-//!     input and output bytes. Currently this library provides the following implementations of
-//!     [`BufferFormatter`] trait: [`UppercaseHexadecimalFormatter`], [`LowercaseHexadecimalFormatter`],
-//!     [`DecimalFormatter`], [`BinaryFormatter`] and [`OctalFormatter`]. Also [`BufferFormatter`] is
-//!     public trait so you are free to construct your own implementation.
-//! -   Filtering part, which must implement [`RecordFilter`] trait provide by this library.
-//!     This part of [`LoggedStream`] is responsible for log records filtering. Currently this
-//!     library provides the following implementation of [`RecordFilter`] trait: [`DefaultFilter`] which
-//!     accepts all log records and [`RecordKindFilter`] which accepts logs with kinds specified during
-//!     construct. Also [`RecordFilter`] is public trait and you are free to construct your own implementation.
-//! -   Logging part, which must implement [`Logger`] trait provided by this library. This part
-//!     of [`LoggedStream`] is responsible for further work with constructed, formatter and filtered
-//!     log record. For example, it can be outputted to console, written to the file, written to database,
-//!     written to the memory for further use or sended by the channel. Currently this library provides
-//!     the following implementations of [`Logger`] trait: [`ConsoleLogger`], [`MemoryStorageLogger`],
-//!     [`ChannelLogger`] and [`FileLogger`]. Also [`Logger`] is public trait and you are free to construct
-//!     your own implementation.
+//! -   Buffer formatting part, which must implement [`BufferFormatter`] trait provided by this library. This
+//!     part of [`LoggedStream`] is responsible for the form you will see the input and output bytes. Currently
+//!     this library provides the following implementations of [`BufferFormatter`] trait:
+//!     [`HexadecimalFormatter`], [`DecimalFormatter`], [`BinaryFormatter`], [`OctalFormatter`],
+//!     [`HexdumpFormatter`], [`HexAsciiFormatter`], [`CEscapeFormatter`] and `Base64Formatter` (requires
+//!     `base64` feature). Output of any formatter can be limited using [`TruncatingFormatter`] wrapper and
+//!     grouped into fixed-width words using [`GroupingFormatter`] wrapper, while length prefix of a frame can
+//!     be separated from payload using [`PrefixSplitFormatter`] wrapper. Read and written bytes can be
+//!     formatted differently using [`DirectionalFormatter`]. Also [`BufferFormatter`] is public trait so you
+//!     are free to construct your own implementation or simply provide byte formatting closure to
+//!     [`FnFormatter`]. Built-in formatters can be selected by name, for example from command line argument,
+//!     using [`parse_formatter`] function.
+//! -   Filtering part, which must implement [`RecordFilter`] trait provide by this library. This part of
+//!     [`LoggedStream`] is responsible for log records filtering. Currently this library provides the following
+//!     implementations of [`RecordFilter`] trait: [`DefaultFilter`] which accepts all log records,
+//!     [`RecordKindFilter`] which accepts logs with kinds specified while constructing, [`ContentFilter`] which
+//!     accepts logs which message contains specified substring, [`RateLimitFilter`] which accepts at most
+//!     specified number of logs per time interval, [`ProbabilisticFilter`] which accepts random fraction of
+//!     logs, [`SizeFilter`] which accepts logs which payload size is within specified bounds,
+//!     [`ByteValueFilter`] which accepts logs which raw payload contains any of specified byte values,
+//!     [`TimeWindowFilter`] which accepts logs created within specified time window, [`DedupFilter`] which
+//!     rejects consecutive duplicates of logs, [`HeadFilter`] which accepts only first specified number of logs
+//!     and `RegexFilter` which accepts logs which message matches specified regular expression (requires
+//!     `regex` feature). Filters can be combined using [`AndFilter`], [`OrFilter`] and [`NotFilter`]. Decisions
+//!     of any filter can be counted using [`CountingFilter`] wrapper. Also [`RecordFilter`] is public trait and
+//!     you are free to construct your own implementation.
+//! -   Logging part, which must implement [`Logger`] trait provided by this library. This part of
+//!     [`LoggedStream`] is responsible for further work with constructed, formatter and filtered log record.
+//!     For example, it can be outputted to console, written to the file, written to database, written to the
+//!     memory for further use or sended by the channel. Currently this library provides the following
+//!     implementations of [`Logger`] trait: [`ConsoleLogger`], [`MemoryStorageLogger`],
+//!     [`SharedMemoryStorageLogger`], [`ByteBudgetLogger`], [`ChannelLogger`], [`FileLogger`], [`WriteLogger`],
+//!     [`RollingFileLogger`], `GzipFileLogger` (requires `flate2` feature), `TracingLogger` (requires `tracing`
+//!     feature), `StructuredLogger` (requires `log-kv` feature), `SqliteLogger` (requires `rusqlite` feature)
+//!     and `SyslogLogger` (requires `syslog` feature). Records can be processed in a dedicated thread using
+//!     [`BackgroundLogger`], passed to several loggers at once using [`MultiLogger`], sampled using
+//!     [`SamplingLogger`], transformed using [`MapLogger`] or discarded using [`NullLogger`]. Asynchronous
+//!     loggers, such as `TokioFileLogger`, implement `AsyncLogger` trait and can be used through
+//!     `SpawnedLogger` which processes records in a separate task (requires `async-logger` feature). Also
+//!     [`Logger`] is public trait so you are free to construct your own implementation or simply provide log
+//!     record processing closure to [`FnLogger`].
+//!
+//! Parts of [`LoggedStream`] can also be specified one by one using [`LoggedStreamBuilder`], which applies
+//! defaults to any omitted part.
+//!
+//! Timestamps of log records are taken from [`SystemClock`] by default. Another source of time implementing
+//! [`Clock`] trait, for example fixed one for deterministic tests, can be provided using
+//! [`LoggedStream::with_clock`] method.
+//!
+//! Datagram sockets can be wrapped into [`LoggedDatagram`], which constructs from the same parts and logs every
+//! sent and received datagram together with its peer address.
 //!
 //! [`Write`]: std::io::Write
 //! [`Read`]: std::io::Read
@@ -32,25 +65,102 @@
 //! [`AsyncWrite`]: tokio::io::AsyncWrite
 
 mod buffer_formatter;
+mod clock;
+mod datagram;
+mod duplex;
 mod filter;
 mod logger;
 mod record;
+mod split;
+mod stdio;
 mod stream;
 
+pub use buffer_formatter::parse_formatter;
+#[cfg(feature = "base64")]
+pub use buffer_formatter::Base64Alphabet;
+#[cfg(feature = "base64")]
+pub use buffer_formatter::Base64Formatter;
 pub use buffer_formatter::BinaryFormatter;
 pub use buffer_formatter::BufferFormatter;
+pub use buffer_formatter::CEscapeFormatter;
 pub use buffer_formatter::DecimalFormatter;
+pub use buffer_formatter::DirectionalFormatter;
+pub use buffer_formatter::DisplayBuffer;
+pub use buffer_formatter::FnFormatter;
+pub use buffer_formatter::GroupingFormatter;
+pub use buffer_formatter::HexAsciiFormatter;
+pub use buffer_formatter::HexCase;
+pub use buffer_formatter::HexadecimalFormatter;
+pub use buffer_formatter::HexdumpFormatter;
+#[allow(deprecated)]
 pub use buffer_formatter::LowercaseHexadecimalFormatter;
 pub use buffer_formatter::OctalFormatter;
+pub use buffer_formatter::PrefixSplitFormatter;
+pub use buffer_formatter::TruncatingFormatter;
+#[allow(deprecated)]
 pub use buffer_formatter::UppercaseHexadecimalFormatter;
+pub use clock::Clock;
+pub use clock::SystemClock;
+pub use datagram::LoggedDatagram;
+pub use duplex::Duplex;
+pub use filter::AndFilter;
+pub use filter::ByteValueFilter;
+pub use filter::ContentFilter;
+pub use filter::CountingFilter;
+pub use filter::DedupFilter;
 pub use filter::DefaultFilter;
+pub use filter::HeadFilter;
+pub use filter::NotFilter;
+pub use filter::OrFilter;
+pub use filter::ProbabilisticFilter;
+pub use filter::RateLimitFilter;
 pub use filter::RecordFilter;
 pub use filter::RecordKindFilter;
+pub use filter::RecordKindFilterMode;
+#[cfg(feature = "regex")]
+pub use filter::RegexFilter;
+pub use filter::SizeFilter;
+pub use filter::TimeWindowFilter;
+#[cfg(feature = "async-logger")]
+pub use logger::AsyncLogger;
+pub use logger::BackgroundLogger;
+pub use logger::ByteBudgetLogger;
 pub use logger::ChannelLogger;
+pub use logger::ColorMode;
 pub use logger::ConsoleLogger;
+pub use logger::ConsoleTarget;
 pub use logger::FileLogger;
+pub use logger::FnLogger;
+#[cfg(feature = "flate2")]
+pub use logger::GzipFileLogger;
 pub use logger::Logger;
+pub use logger::MapLogger;
 pub use logger::MemoryStorageLogger;
+pub use logger::MultiLogger;
+pub use logger::NullLogger;
+pub use logger::RollingFileLogger;
+pub use logger::SamplingLogger;
+pub use logger::SharedMemoryStorageLogger;
+#[cfg(feature = "async-logger")]
+pub use logger::SpawnedLogger;
+#[cfg(feature = "rusqlite")]
+pub use logger::SqliteLogger;
+#[cfg(feature = "log-kv")]
+pub use logger::StructuredLogger;
+#[cfg(feature = "syslog")]
+pub use logger::SyslogLogger;
+#[cfg(feature = "async-logger")]
+pub use logger::TokioFileLogger;
+#[cfg(feature = "tracing")]
+pub use logger::TracingLogger;
+pub use logger::WriteLogger;
+pub use record::ParseRecordKindError;
 pub use record::Record;
 pub use record::RecordKind;
+pub use split::LoggedReadHalf;
+pub use split::LoggedWriteHalf;
+pub use stdio::StdioStream;
+pub use stream::LoggedDuplex;
 pub use stream::LoggedStream;
+pub use stream::LoggedStreamBuilder;
+pub use stream::Stats;
@@ -1,9 +1,24 @@
 use crate::record::Record;
 use crate::RecordKind;
+use chrono::format::Item;
+use chrono::format::StrftimeItems;
 use std::collections;
+use std::env;
+use std::fmt;
+use std::fs;
+#[cfg(feature = "async-logger")]
+use std::future::Future;
+use std::io;
 use std::io::Write;
+use std::path;
+#[cfg(feature = "async-logger")]
+use std::pin::Pin;
 use std::str::FromStr;
+use std::sync;
 use std::sync::mpsc;
+use std::thread;
+
+const DEFAULT_TIME_FORMAT: &str = "%+";
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Trait
@@ -25,12 +40,25 @@ pub trait Logger: Send + 'static {
     fn log(&mut self, record: Record);
 }
 
-impl Logger for Box<dyn Logger> {
-    fn log(&mut self, record: Record) {
-        (**self).log(record)
-    }
+macro_rules! impl_logger_for_boxed {
+    ($($object:ty),+ $(,)?) => {
+        $(
+            impl Logger for Box<$object> {
+                fn log(&mut self, record: Record) {
+                    (**self).log(record)
+                }
+            }
+        )+
+    };
 }
 
+impl_logger_for_boxed!(
+    dyn Logger,
+    dyn Logger + Send,
+    dyn Logger + Sync,
+    dyn Logger + Send + Sync,
+);
+
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // ConsoleLogger
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -38,13 +66,56 @@ impl Logger for Box<dyn Logger> {
 /// Logger implementation that writes log records to the console.
 ///
 /// This implementation of the [`Logger`] trait writes log records ([`Record`]) to the console using the provided
-/// [`log::Level`]. Log records with the [`Error`] kind ignore the provided [`log::Level`] and are always written
-/// with [`log::Level::Error`].
+/// [`log::Level`]. Log records with the [`Error`] kind ignore the provided [`log::Level`] and are written with
+/// [`log::Level::Error`]. Level of any log record kind can be overridden using [`with_level_for`] method.
+///
+/// By default log records are passed to the [`log`] facade. Using [`with_target`] method they can be written
+/// directly to standard output or standard error stream instead, bypassing the facade and provided
+/// [`log::Level`]. Also log records can be colorized by their kind using ANSI escape sequences, which is
+/// configured using [`with_color`] method. Log record timestamp can be prefixed to every line using
+/// [`with_timestamp`] method, which is useful when logging backend does not output time by itself. When several
+/// streams are logged to the same console, their lines can be distinguished by fixed prefix set using
+/// [`with_prefix`] method.
 ///
 /// [`Error`]: crate::RecordKind::Error
+/// [`with_target`]: ConsoleLogger::with_target
+/// [`with_color`]: ConsoleLogger::with_color
+/// [`with_timestamp`]: ConsoleLogger::with_timestamp
+/// [`with_prefix`]: ConsoleLogger::with_prefix
+/// [`with_level_for`]: ConsoleLogger::with_level_for
 #[derive(Debug, Clone)]
 pub struct ConsoleLogger {
     level: log::Level,
+    levels: collections::HashMap<RecordKind, log::Level>,
+    target: ConsoleTarget,
+    colored: bool,
+    timestamp: bool,
+    byte_count: bool,
+    prefix: Option<String>,
+    wrap: Option<usize>,
+}
+
+/// Output target of [`ConsoleLogger`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ConsoleTarget {
+    /// Pass log records to the [`log`] facade.
+    #[default]
+    Log,
+    /// Write log records directly to standard output stream ([`io::stdout`]).
+    Stdout,
+    /// Write log records directly to standard error stream ([`io::stderr`]).
+    Stderr,
+}
+
+/// Colorization mode of [`ConsoleLogger`] output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorMode {
+    /// Colorize log records unless `NO_COLOR` environment variable is set to a non-empty value.
+    Auto,
+    /// Always colorize log records.
+    Always,
+    /// Never colorize log records.
+    Never,
 }
 
 impl ConsoleLogger {
@@ -52,7 +123,16 @@ impl ConsoleLogger {
     /// case if provided log level [`str`] was incorrect.
     pub fn new(level: &str) -> Result<Self, log::ParseLevelError> {
         let level = log::Level::from_str(level)?;
-        Ok(Self { level })
+        Ok(Self {
+            level,
+            levels: collections::HashMap::new(),
+            target: ConsoleTarget::default(),
+            colored: false,
+            timestamp: false,
+            byte_count: false,
+            prefix: None,
+            wrap: None,
+        })
     }
 
     /// Construct a new instance of [`ConsoleLogger`] using provided log level [`str`]. Panics in case if
@@ -60,15 +140,204 @@ impl ConsoleLogger {
     pub fn new_unchecked(level: &str) -> Self {
         Self::new(level).unwrap()
     }
+
+    /// Set output target of log records. It is [`ConsoleTarget::Log`] by default.
+    pub fn with_target(mut self, target: ConsoleTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Set colorization mode of log records. It is [`ColorMode::Never`] by default. In case of [`ColorMode::Auto`]
+    /// the `NO_COLOR` environment variable is checked once during this call.
+    pub fn with_color(mut self, mode: ColorMode) -> Self {
+        self.colored = match mode {
+            ColorMode::Auto => env::var_os("NO_COLOR").map_or(true, |value| value.is_empty()),
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        };
+        self
+    }
+
+    /// Enable or disable prefixing of every line with log record timestamp in RFC 3339 format. It is disabled by
+    /// default to avoid duplicate timestamps when logging backend outputs them by itself.
+    pub fn with_timestamp(mut self, timestamp: bool) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Enable or disable appending of real number of transferred bytes ([`Record::byte_count`]) to every line, for
+    /// example `< 0a:0b … (+65000 more bytes) (65002 bytes)`. It is useful when message is shortened by
+    /// [`TruncatingFormatter`]. Log records without byte count are not affected. It is disabled by default.
+    ///
+    /// [`TruncatingFormatter`]: crate::TruncatingFormatter
+    pub fn with_byte_count(mut self, byte_count: bool) -> Self {
+        self.byte_count = byte_count;
+        self
+    }
+
+    /// Set fixed prefix which is prepended to every line, for example `[conn-7]`, so output of several streams
+    /// logged to the same console can be told apart. There is no prefix by default.
+    pub fn with_prefix(mut self, prefix: String) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Set log level for log records of provided kind, for example [`log::Level::Debug`] for reads and
+    /// [`log::Level::Warn`] for shutdown. Kinds without configured level are logged using the level provided on
+    /// construction, except the [`Error`] kind which falls back to [`log::Level::Error`]. It has effect only in
+    /// case of [`ConsoleTarget::Log`] output target.
+    ///
+    /// [`Error`]: RecordKind::Error
+    pub fn with_level_for(mut self, kind: RecordKind, level: log::Level) -> Self {
+        self.levels.insert(kind, level);
+        self
+    }
+
+    /// Set maximum width of log record message after which it is split into several lines, so very long formatted
+    /// buffers don't wrap unpredictably in terminal. Message is split at separator boundaries, such as `:` or
+    /// space, where possible and separator at the boundary is omitted. Continuation lines are indented instead of
+    /// repeating log record kind, while prefix and timestamp are repeated on every line. Each line is logged
+    /// separately. Messages are not wrapped by default.
+    pub fn with_wrap(mut self, width: usize) -> Self {
+        self.wrap = Some(width.max(1));
+        self
+    }
+
+    fn level_for(&self, kind: RecordKind) -> log::Level {
+        match self.levels.get(&kind) {
+            Some(level) => *level,
+            None if kind == RecordKind::Error => log::Level::Error,
+            None => self.level,
+        }
+    }
+
+    fn format_record(&self, record: &Record) -> String {
+        self.format_line(record, &record.kind.to_string(), &record.message, true)
+    }
+
+    fn format_lines(&self, record: &Record) -> Vec<String> {
+        let width = match self.wrap {
+            Some(width) => width,
+            None => return vec![self.format_record(record)],
+        };
+        let chunks = wrap_message(&record.message, width);
+        let last = chunks.len() - 1;
+        chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                // Continuation lines are indented by the width of log record kind.
+                let head = match index {
+                    0 => record.kind.to_string(),
+                    _ => String::from(" "),
+                };
+                self.format_line(record, &head, chunk, index == last)
+            })
+            .collect()
+    }
+
+    fn format_line(&self, record: &Record, head: &str, message: &str, last: bool) -> String {
+        let mut line = self.format_colored(record.kind, head, message, record.byte_count, last);
+        if self.timestamp {
+            line = format!("{} {line}", record.time.format(DEFAULT_TIME_FORMAT));
+        }
+        match &self.prefix {
+            Some(prefix) => format!("{prefix} {line}"),
+            None => line,
+        }
+    }
+
+    fn format_colored(
+        &self,
+        kind: RecordKind,
+        head: &str,
+        message: &str,
+        byte_count: Option<usize>,
+        last: bool,
+    ) -> String {
+        let line = match byte_count {
+            Some(byte_count) if self.byte_count && last => {
+                format!("{head} {message} ({byte_count} bytes)")
+            }
+            _ => format!("{head} {message}"),
+        };
+        if !self.colored {
+            return line;
+        }
+        let color = match kind {
+            RecordKind::Open => "36",
+            RecordKind::Connect => "96",
+            RecordKind::Read => "32",
+            RecordKind::Eof => "92",
+            RecordKind::Write => "34",
+            RecordKind::Error => "31",
+            RecordKind::Shutdown => "33",
+            RecordKind::Flush => "35",
+            RecordKind::Seek => "37",
+            RecordKind::Pending => "93",
+            RecordKind::Drop => "90",
+        };
+        format!("\x1b[{color}m{line}\x1b[0m")
+    }
+}
+
+/// Splits provided message into chunks of at most `width` chars. Chunk boundary is moved back to the last
+/// non-alphanumeric char, which is most likely a separator inserted by formatting part, if there is one.
+fn wrap_message(message: &str, width: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = message;
+    while rest.chars().count() > width {
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map_or(rest.len(), |(i, _)| i);
+        // Separator right after the chunk is a boundary as well, so one more char is inspected.
+        let limit = rest
+            .char_indices()
+            .nth(width + 1)
+            .map_or(rest.len(), |(i, _)| i);
+        match rest[..limit].rfind(|c: char| !c.is_alphanumeric()) {
+            Some(index) if index > 0 => {
+                chunks.push(&rest[..index]);
+                let separator = rest[index..].chars().next().map_or(0, char::len_utf8);
+                rest = &rest[index + separator..];
+            }
+            _ => {
+                chunks.push(&rest[..end]);
+                rest = &rest[end..];
+            }
+        }
+    }
+    if !rest.is_empty() || chunks.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+/// Returns provided [`strftime`] pattern if it is valid, so it can be used to format log record timestamp without
+/// panicking, otherwise returns `None`.
+///
+/// [`strftime`]: chrono::format::strftime
+fn validated_time_format(time_format: String) -> Option<String> {
+    StrftimeItems::new(&time_format)
+        .all(|item| !matches!(item, Item::Error))
+        .then_some(time_format)
 }
 
 impl Logger for ConsoleLogger {
     fn log(&mut self, record: Record) {
-        let level = match record.kind {
-            RecordKind::Error => log::Level::Error,
-            _ => self.level,
-        };
-        log::log!(level, "{} {}", record.kind, record.message)
+        let level = self.level_for(record.kind);
+        for line in self.format_lines(&record) {
+            match self.target {
+                ConsoleTarget::Log => log::log!(level, "{line}"),
+                ConsoleTarget::Stdout => {
+                    let _ = writeln!(io::stdout().lock(), "{line}");
+                }
+                ConsoleTarget::Stderr => {
+                    let _ = writeln!(io::stderr().lock(), "{line}");
+                }
+            }
+        }
     }
 }
 
@@ -87,23 +356,37 @@ impl Logger for Box<ConsoleLogger> {
 /// This implementation of the [`Logger`] trait writes log records ([`Record`]) into an inner collection
 /// ([`collections::VecDeque`]). The length of the inner collection is limited by a number provided during
 /// structure construction. You can retrieve accumulated log records from the inner collection using the
-/// [`get_log_records`] method and clear the inner collection using the [`clear_log_records`] method.
+/// [`get_log_records`] method and clear the inner collection using the [`clear_log_records`] method. Also
+/// accumulated log records can be moved out of the inner collection without cloning using the
+/// [`drain_log_records`] method or passed to another logger using the [`replay_into`] method.
 ///
 /// [`VecDeque`]: collections::VecDeque
 /// [`get_log_records`]: MemoryStorageLogger::get_log_records
 /// [`clear_log_records`]: MemoryStorageLogger::clear_log_records
+/// [`drain_log_records`]: MemoryStorageLogger::drain_log_records
+/// [`replay_into`]: MemoryStorageLogger::replay_into
 #[derive(Debug, Clone)]
 pub struct MemoryStorageLogger {
     storage: collections::VecDeque<Record>,
-    max_length: usize,
+    max_length: Option<usize>,
 }
 
 impl MemoryStorageLogger {
-    /// Construct a new instance of [`MemoryStorageLogger`] using provided inner collection max length number,
+    /// Construct a new instance of [`MemoryStorageLogger`] using provided inner collection max length number.
+    /// When the inner collection is full, the oldest log record is removed to make space for the new one. In
+    /// case if provided max length is `0`, log records are not stored at all.
     pub fn new(max_length: usize) -> Self {
         Self {
             storage: collections::VecDeque::new(),
-            max_length,
+            max_length: Some(max_length),
+        }
+    }
+
+    /// Construct a new instance of [`MemoryStorageLogger`] which inner collection length is not limited.
+    pub fn new_unbounded() -> Self {
+        Self {
+            storage: collections::VecDeque::new(),
+            max_length: None,
         }
     }
 
@@ -118,14 +401,47 @@ impl MemoryStorageLogger {
     pub fn clear_log_records(&mut self) {
         self.storage.clear()
     }
+
+    /// Move log records out of inner collection without cloning, leaving it empty.
+    #[inline]
+    pub fn drain_log_records(&mut self) -> collections::VecDeque<Record> {
+        std::mem::take(&mut self.storage)
+    }
+
+    /// Pass clones of all log records from inner collection to provided logger in the order they were logged.
+    /// Inner collection is left untouched, use [`drain_log_records`] to move log records out of it instead.
+    ///
+    /// [`drain_log_records`]: MemoryStorageLogger::drain_log_records
+    pub fn replay_into(&self, logger: &mut dyn Logger) {
+        for record in self.storage.iter() {
+            logger.log(record.clone());
+        }
+    }
+
+    /// Retrieve the number of log records in inner collection.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns `true` if inner collection contains no log records.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
 }
 
 impl Logger for MemoryStorageLogger {
     fn log(&mut self, record: Record) {
-        self.storage.push_back(record);
-        if self.storage.len() > self.max_length {
-            let _ = self.storage.pop_front();
+        if let Some(max_length) = self.max_length {
+            if max_length == 0 {
+                return;
+            }
+            while self.storage.len() >= max_length {
+                let _ = self.storage.pop_front();
+            }
         }
+        self.storage.push_back(record);
     }
 }
 
@@ -135,6 +451,183 @@ impl Logger for Box<MemoryStorageLogger> {
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SharedMemoryStorageLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that writes log records to an inner [`VecDeque`] collection shared between clones.
+///
+/// This implementation of the [`Logger`] trait works the same way as [`MemoryStorageLogger`], but its inner
+/// collection is placed behind [`Arc`] and [`Mutex`]. All clones of [`SharedMemoryStorageLogger`] refer to the
+/// same inner collection, so one clone can be passed to [`LoggedStream`] while another one is kept to read
+/// accumulated log records from any thread using the [`snapshot`] method while IO is still in progress.
+///
+/// [`VecDeque`]: collections::VecDeque
+/// [`Arc`]: sync::Arc
+/// [`Mutex`]: sync::Mutex
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`snapshot`]: SharedMemoryStorageLogger::snapshot
+#[derive(Debug, Clone)]
+pub struct SharedMemoryStorageLogger {
+    inner: sync::Arc<sync::Mutex<MemoryStorageLogger>>,
+}
+
+impl SharedMemoryStorageLogger {
+    /// Construct a new instance of [`SharedMemoryStorageLogger`] using provided inner collection max length
+    /// number. Behavior on overflow is the same as for [`MemoryStorageLogger::new`].
+    pub fn new(max_length: usize) -> Self {
+        Self::from_logger(MemoryStorageLogger::new(max_length))
+    }
+
+    /// Construct a new instance of [`SharedMemoryStorageLogger`] which inner collection length is not limited.
+    pub fn new_unbounded() -> Self {
+        Self::from_logger(MemoryStorageLogger::new_unbounded())
+    }
+
+    fn from_logger(logger: MemoryStorageLogger) -> Self {
+        Self {
+            inner: sync::Arc::new(sync::Mutex::new(logger)),
+        }
+    }
+
+    fn lock(&self) -> sync::MutexGuard<'_, MemoryStorageLogger> {
+        match self.inner.lock() {
+            Ok(inner) => inner,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Retrieve clones of log records accumulated in inner collection at the moment of call.
+    pub fn snapshot(&self) -> collections::VecDeque<Record> {
+        self.lock().get_log_records()
+    }
+
+    /// Clear inner collection of log records. It affects all clones.
+    pub fn clear_log_records(&self) {
+        self.lock().clear_log_records()
+    }
+
+    /// Move log records out of inner collection without cloning, leaving it empty. It affects all clones.
+    pub fn drain_log_records(&self) -> collections::VecDeque<Record> {
+        self.lock().drain_log_records()
+    }
+
+    /// Retrieve the number of log records in inner collection.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if inner collection contains no log records.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+}
+
+impl Logger for SharedMemoryStorageLogger {
+    fn log(&mut self, record: Record) {
+        self.lock().log(record)
+    }
+}
+
+impl Logger for Box<SharedMemoryStorageLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ByteBudgetLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that keeps log records in an inner [`VecDeque`] collection limited by total message size.
+///
+/// Unlike [`MemoryStorageLogger`], which limits the number of stored log records, this implementation of the
+/// [`Logger`] trait limits the summed length of their messages in bytes. After each log record is pushed, the
+/// oldest log records are evicted until total message length doesn't exceed the budget, so memory usage is
+/// bounded regardless of how much log record sizes vary. A log record which message alone is longer than the
+/// budget is evicted right away.
+///
+/// [`VecDeque`]: collections::VecDeque
+#[derive(Debug, Clone)]
+pub struct ByteBudgetLogger {
+    storage: collections::VecDeque<Record>,
+    max_bytes: usize,
+    total_bytes: usize,
+}
+
+impl ByteBudgetLogger {
+    /// Construct a new instance of [`ByteBudgetLogger`] using provided maximum total length of stored log record
+    /// messages in bytes.
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            storage: collections::VecDeque::new(),
+            max_bytes,
+            total_bytes: 0,
+        }
+    }
+
+    /// Retrieve log records from inner collection.
+    #[inline]
+    pub fn get_log_records(&self) -> collections::VecDeque<Record> {
+        self.storage.clone()
+    }
+
+    /// Clear inner collection of log records, releasing the whole budget.
+    pub fn clear_log_records(&mut self) {
+        self.storage.clear();
+        self.total_bytes = 0;
+    }
+
+    /// Move log records out of inner collection without cloning, leaving it empty.
+    pub fn drain_log_records(&mut self) -> collections::VecDeque<Record> {
+        self.total_bytes = 0;
+        std::mem::take(&mut self.storage)
+    }
+
+    /// Retrieve the number of log records in inner collection.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns `true` if inner collection contains no log records.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// Retrieve summed length of messages of stored log records in bytes.
+    #[inline]
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Retrieve maximum summed length of stored messages in bytes, provided during construction.
+    #[inline]
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+}
+
+impl Logger for ByteBudgetLogger {
+    fn log(&mut self, record: Record) {
+        self.total_bytes += record.message.len();
+        self.storage.push_back(record);
+        while self.total_bytes > self.max_bytes {
+            match self.storage.pop_front() {
+                Some(evicted) => self.total_bytes -= evicted.message.len(),
+                None => break,
+            }
+        }
+    }
+}
+
+impl Logger for Box<ByteBudgetLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // ChannelLogger
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -145,24 +638,86 @@ impl Logger for Box<MemoryStorageLogger> {
 /// asynchronous channel. You can obtain the receiving-half of the channel using the [`take_receiver`] and
 /// [`take_receiver_unchecked`] methods.
 ///
+/// By default the underlying channel is unbounded. A bounded channel can be used instead by constructing
+/// [`ChannelLogger`] with the [`bounded`] method. In this case log records which don't fit into the channel buffer
+/// are dropped instead of blocking IO operations, and their number can be retrieved using the [`dropped_count`]
+/// method.
+///
+/// Additional receivers of the same log records stream can be created using the [`subscribe`] method. Each log
+/// record is sent to all live receivers, and channels which receivers were dropped are pruned.
+///
 /// [`take_receiver`]: ChannelLogger::take_receiver
 /// [`take_receiver_unchecked`]: ChannelLogger::take_receiver_unchecked
+/// [`bounded`]: ChannelLogger::bounded
+/// [`dropped_count`]: ChannelLogger::dropped_count
+/// [`subscribe`]: ChannelLogger::subscribe
 #[derive(Debug)]
 pub struct ChannelLogger {
-    sender: mpsc::Sender<Record>,
+    senders: Vec<ChannelSender>,
     receiver: Option<mpsc::Receiver<Record>>,
+    capacity: Option<usize>,
+    dropped_count: usize,
+}
+
+#[derive(Debug)]
+enum ChannelSender {
+    Unbounded(mpsc::Sender<Record>),
+    Bounded(mpsc::SyncSender<Record>),
+}
+
+impl ChannelSender {
+    fn channel(capacity: Option<usize>) -> (Self, mpsc::Receiver<Record>) {
+        match capacity {
+            Some(capacity) => {
+                let (sender, receiver) = mpsc::sync_channel(capacity);
+                (Self::Bounded(sender), receiver)
+            }
+            None => {
+                let (sender, receiver) = mpsc::channel();
+                (Self::Unbounded(sender), receiver)
+            }
+        }
+    }
 }
 
 impl ChannelLogger {
-    /// Construct a new instance of [`ChannelLogger`].
+    /// Construct a new instance of [`ChannelLogger`] using unbounded channel.
     pub fn new() -> Self {
-        let (sender, receiver) = mpsc::channel();
+        Self::with_capacity(None)
+    }
+
+    /// Construct a new instance of [`ChannelLogger`] using bounded channel with provided capacity. Log records
+    /// which are sent while the channel buffer is full are dropped.
+    pub fn bounded(capacity: usize) -> Self {
+        Self::with_capacity(Some(capacity))
+    }
+
+    fn with_capacity(capacity: Option<usize>) -> Self {
+        let (sender, receiver) = ChannelSender::channel(capacity);
         Self {
-            sender,
+            senders: vec![sender],
             receiver: Some(receiver),
+            capacity,
+            dropped_count: 0,
         }
     }
 
+    /// Create an additional receiving-half which receives all log records sent after this call. In case if this
+    /// [`ChannelLogger`] was constructed using the [`bounded`] method, the new channel has the same capacity.
+    ///
+    /// [`bounded`]: ChannelLogger::bounded
+    pub fn subscribe(&mut self) -> mpsc::Receiver<Record> {
+        let (sender, receiver) = ChannelSender::channel(self.capacity);
+        self.senders.push(sender);
+        receiver
+    }
+
+    /// Retrieve the number of log records which were dropped because the channel buffer was full.
+    #[inline]
+    pub fn dropped_count(&self) -> usize {
+        self.dropped_count
+    }
+
     /// Take channel receiving-half. Returns [`None`] if it was already taken.
     #[inline]
     pub fn take_receiver(&mut self) -> Option<mpsc::Receiver<Record>> {
@@ -183,7 +738,17 @@ impl Default for ChannelLogger {
 
 impl Logger for ChannelLogger {
     fn log(&mut self, record: Record) {
-        let _ = self.sender.send(record);
+        self.senders.retain(|sender| match sender {
+            ChannelSender::Unbounded(sender) => sender.send(record.clone()).is_ok(),
+            ChannelSender::Bounded(sender) => match sender.try_send(record.clone()) {
+                Ok(()) => true,
+                Err(mpsc::TrySendError::Full(_)) => {
+                    self.dropped_count += 1;
+                    true
+                }
+                Err(mpsc::TrySendError::Disconnected(_)) => false,
+            },
+        });
     }
 }
 
@@ -194,76 +759,2248 @@ impl Logger for Box<ChannelLogger> {
 }
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// FileLogger
+// WriteLogger
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// This implementation of [`Logger`] trait writes log records ([`Record`]) into provided file.
-pub struct FileLogger {
-    file: std::fs::File,
+/// This implementation of [`Logger`] trait writes log records ([`Record`]) into provided writer, for example
+/// [`Vec<u8>`], [`io::Cursor`] or a pipe, one `[time] kind message` line per log record.
+///
+/// Log records are written through an inner buffer, which is flushed when [`flush`] method is called and when
+/// [`WriteLogger`] is dropped. In case if flushing of each log record is enabled using [`with_flush_each_record`]
+/// method, the buffer is flushed right after every written log record, so no log records are lost even if the
+/// process is killed.
+///
+/// Log record timestamp is written in RFC 3339 format by default, which can be changed to any [`strftime`]
+/// pattern using [`with_time_format`] method.
+///
+/// [`flush`]: WriteLogger::flush
+/// [`with_flush_each_record`]: WriteLogger::with_flush_each_record
+/// [`with_time_format`]: WriteLogger::with_time_format
+/// [`strftime`]: chrono::format::strftime
+pub struct WriteLogger<W: io::Write + Send + 'static> {
+    writer: io::BufWriter<W>,
+    flush_each_record: bool,
+    time_format: String,
+}
+
+/// [`WriteLogger`] which writes log records into a file. It can be constructed from an already opened file using
+/// [`WriteLogger::new`] method or by path using [`FileLogger::open`] method.
+pub type FileLogger = WriteLogger<fs::File>;
+
+impl<W: io::Write + Send + 'static> WriteLogger<W> {
+    /// Construct a new instance of [`WriteLogger`] using provided writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: io::BufWriter::new(writer),
+            flush_each_record: false,
+            time_format: String::from(DEFAULT_TIME_FORMAT),
+        }
+    }
+
+    /// Enable or disable flushing of the inner buffer after every written log record. It is disabled by default.
+    pub fn with_flush_each_record(mut self, flush_each_record: bool) -> Self {
+        self.flush_each_record = flush_each_record;
+        self
+    }
+
+    /// Set [`strftime`] pattern used to format log record timestamp. It is `%+` (RFC 3339) by default. In case if
+    /// provided pattern is invalid, the default one is kept, so logging never panics because of it.
+    ///
+    /// [`strftime`]: chrono::format::strftime
+    pub fn with_time_format(mut self, time_format: String) -> Self {
+        if let Some(time_format) = validated_time_format(time_format) {
+            self.time_format = time_format;
+        }
+        self
+    }
+
+    /// Flush the inner buffer, ensuring that all written log records reach the writer.
+    #[inline]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns a shared reference to the underlying writer. Log records which are still kept in the inner buffer
+    /// are not visible through it until [`flush`] method is called.
+    ///
+    /// [`flush`]: WriteLogger::flush
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        self.writer.get_ref()
+    }
 }
 
 impl FileLogger {
-    /// Construct a new instance of [`FileLogger`] using provided file.
-    pub fn new(file: std::fs::File) -> Self {
-        Self { file }
+    /// Construct a new instance of [`FileLogger`] by opening a file located at provided path. The file is created
+    /// if it does not exist. In case if `append` is `true`, new log records are appended to the end of the existing
+    /// file, otherwise the file is truncated. Returns an [`Err`] in case if the file can't be opened.
+    pub fn open<P: AsRef<path::Path>>(path: P, append: bool) -> io::Result<Self> {
+        let mut options = fs::OpenOptions::new();
+        options.create(true);
+        if append {
+            options.append(true);
+        } else {
+            options.write(true).truncate(true);
+        }
+        Ok(Self::new(options.open(path)?))
     }
 }
 
-impl Logger for FileLogger {
+impl<W: io::Write + Send + 'static> Logger for WriteLogger<W> {
     fn log(&mut self, record: Record) {
         let _ = writeln!(
-            self.file,
+            self.writer,
             "[{}] {} {}",
-            record.time.format("%+"),
+            record.time.format(&self.time_format),
             record.kind,
             record.message
         );
+        if self.flush_each_record {
+            let _ = self.writer.flush();
+        }
     }
 }
 
-impl Logger for Box<FileLogger> {
+impl<W: io::Write + Send + 'static> Logger for Box<WriteLogger<W>> {
     fn log(&mut self, record: Record) {
         (**self).log(record)
     }
 }
 
+impl<W: io::Write + Send + 'static> Drop for WriteLogger<W> {
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// Tests
+// RollingFileLogger
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[cfg(test)]
-mod tests {
-    use crate::logger::ChannelLogger;
-    use crate::logger::ConsoleLogger;
-    use crate::logger::FileLogger;
-    use crate::logger::Logger;
-    use crate::logger::MemoryStorageLogger;
-    use crate::record::Record;
-    use crate::record::RecordKind;
+/// Logger implementation that writes log records into a file and rotates it by size.
+///
+/// This implementation of the [`Logger`] trait writes log records ([`Record`]) into a file located at provided
+/// base path. When writing the next log record would make the file size exceed provided `max_bytes` threshold,
+/// the current file is renamed with a numeric suffix (`.1`, `.2`, ...) and a fresh file is opened. Older
+/// rotations are shifted to the next suffix, and at most `max_files` rotations are kept on disk.
+#[derive(Debug)]
+pub struct RollingFileLogger {
+    base_path: path::PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: fs::File,
+    current_size: u64,
+}
 
-    fn assert_unpin<T: Unpin>() {}
+impl RollingFileLogger {
+    /// Construct a new instance of [`RollingFileLogger`] using provided base path, size threshold and max number
+    /// of kept rotations. If the file at base path already exists, new log records are appended to it. Returns
+    /// an [`Err`] in case if the file can't be opened.
+    pub fn new<P: AsRef<path::Path>>(
+        base_path: P,
+        max_bytes: u64,
+        max_files: usize,
+    ) -> io::Result<Self> {
+        let base_path = base_path.as_ref().to_path_buf();
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&base_path)?;
+        let current_size = file.metadata()?.len();
+        Ok(Self {
+            base_path,
+            max_bytes,
+            max_files,
+            file,
+            current_size,
+        })
+    }
 
-    #[test]
-    fn test_unpin() {
-        assert_unpin::<ConsoleLogger>();
-        assert_unpin::<ChannelLogger>();
-        assert_unpin::<MemoryStorageLogger>();
-        assert_unpin::<FileLogger>();
+    fn rotation_path(&self, index: usize) -> path::PathBuf {
+        let mut path = self.base_path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
     }
 
-    #[test]
-    fn test_trait_object_safety() {
-        // Assert traint object construct.
-        let mut console: Box<dyn Logger> = Box::new(ConsoleLogger::new_unchecked("debug"));
-        let mut memory: Box<dyn Logger> = Box::new(MemoryStorageLogger::new(100));
-        let mut channel: Box<dyn Logger> = Box::new(ChannelLogger::new());
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.max_files == 0 {
+            fs::remove_file(&self.base_path)?;
+        } else {
+            let oldest = self.rotation_path(self.max_files);
+            if oldest.exists() {
+                fs::remove_file(oldest)?;
+            }
+            for index in (1..self.max_files).rev() {
+                let from = self.rotation_path(index);
+                if from.exists() {
+                    fs::rename(from, self.rotation_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.base_path, self.rotation_path(1))?;
+        }
 
-        let record = Record::new(RecordKind::Open, String::from("test log record"));
+        self.file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.base_path)?;
+        self.current_size = 0;
+        Ok(())
+    }
+}
 
-        // Assert that trait object methods are dispatchable.
-        console.log(record.clone());
-        memory.log(record.clone());
-        channel.log(record);
+impl Logger for RollingFileLogger {
+    fn log(&mut self, record: Record) {
+        let line = format!(
+            "[{}] {} {}\n",
+            record.time.format(DEFAULT_TIME_FORMAT),
+            record.kind,
+            record.message
+        );
+        let length = line.len() as u64;
+
+        if self.current_size > 0 && self.current_size + length > self.max_bytes {
+            let _ = self.rotate();
+        }
+
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.current_size += length;
+        }
+    }
+}
+
+impl Logger for Box<RollingFileLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// GzipFileLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`Logger`] trait writes gzip-compressed log records ([`Record`]) into provided file.
+///
+/// It writes log records in the same format as [`FileLogger`], but passes them through gzip encoder, trading
+/// CPU time for much smaller size of long-running captures. Gzip stream is finished when [`GzipFileLogger`] is
+/// dropped, so the file can be decompressed only after that, unless [`flush`] method was called. It is
+/// available only when `flate2` feature is enabled.
+///
+/// [`flush`]: GzipFileLogger::flush
+#[cfg(feature = "flate2")]
+pub struct GzipFileLogger {
+    encoder: flate2::write::GzEncoder<fs::File>,
+    time_format: String,
+}
+
+#[cfg(feature = "flate2")]
+impl GzipFileLogger {
+    /// Construct a new instance of [`GzipFileLogger`] using provided file and default compression level.
+    pub fn new(file: fs::File) -> Self {
+        Self::with_compression(file, flate2::Compression::default())
+    }
+
+    /// Construct a new instance of [`GzipFileLogger`] using provided file and compression level.
+    pub fn with_compression(file: fs::File, compression: flate2::Compression) -> Self {
+        Self {
+            encoder: flate2::write::GzEncoder::new(file, compression),
+            time_format: String::from(DEFAULT_TIME_FORMAT),
+        }
+    }
+
+    /// Construct a new instance of [`GzipFileLogger`] by opening a file located at provided path. The file is
+    /// created if it does not exist. In case if `append` is `true`, new gzip stream is appended to the end of the
+    /// existing file, which is still valid gzip file, otherwise the file is truncated. Returns an [`Err`] in case
+    /// if the file can't be opened.
+    pub fn open<P: AsRef<path::Path>>(path: P, append: bool) -> io::Result<Self> {
+        let mut options = fs::OpenOptions::new();
+        options.create(true);
+        if append {
+            options.append(true);
+        } else {
+            options.write(true).truncate(true);
+        }
+        Ok(Self::new(options.open(path)?))
+    }
+
+    /// Set [`strftime`] pattern used to format log record timestamp. It is `%+` (RFC 3339) by default. In case if
+    /// provided pattern is invalid, the default one is kept, so logging never panics because of it.
+    ///
+    /// [`strftime`]: chrono::format::strftime
+    pub fn with_time_format(mut self, time_format: String) -> Self {
+        if let Some(time_format) = validated_time_format(time_format) {
+            self.time_format = time_format;
+        }
+        self
+    }
+
+    /// Flush compressed data written so far into the file, so it can be decompressed before the gzip stream is
+    /// finished. Frequent flushing worsens compression ratio.
+    #[inline]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.encoder.flush()
+    }
+}
+
+#[cfg(feature = "flate2")]
+impl fmt::Debug for GzipFileLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GzipFileLogger")
+            .field("time_format", &self.time_format)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "flate2")]
+impl Logger for GzipFileLogger {
+    fn log(&mut self, record: Record) {
+        let _ = writeln!(
+            self.encoder,
+            "[{}] {} {}",
+            record.time.format(&self.time_format),
+            record.kind,
+            record.message
+        );
+    }
+}
+
+#[cfg(feature = "flate2")]
+impl Logger for Box<GzipFileLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+#[cfg(feature = "flate2")]
+impl Drop for GzipFileLogger {
+    fn drop(&mut self) {
+        let _ = self.encoder.try_finish();
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TracingLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that emits log records as [`tracing`] events.
+///
+/// This implementation of the [`Logger`] trait emits an event for each log record ([`Record`]) using the
+/// provided [`tracing::Level`], with log record kind and message passed as `kind` and `message` fields.
+/// Log records with the [`Error`] kind ignore the provided [`tracing::Level`] and are always emitted
+/// with [`tracing::Level::ERROR`]. It is available only when `tracing` feature is enabled.
+///
+/// [`Error`]: crate::RecordKind::Error
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone)]
+pub struct TracingLogger {
+    level: tracing::Level,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingLogger {
+    /// Construct a new instance of [`TracingLogger`] using provided level [`str`]. Returns an [`Err`] in
+    /// case if provided level [`str`] was incorrect.
+    pub fn new(level: &str) -> Result<Self, tracing::metadata::ParseLevelError> {
+        let level = tracing::Level::from_str(level)?;
+        Ok(Self { level })
+    }
+
+    /// Construct a new instance of [`TracingLogger`] using provided level [`str`]. Panics in case if
+    /// provided level [`str`] was incorrect.
+    pub fn new_unchecked(level: &str) -> Self {
+        Self::new(level).unwrap()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Logger for TracingLogger {
+    fn log(&mut self, record: Record) {
+        macro_rules! emit {
+            ($level:expr) => {
+                tracing::event!($level, kind = ?record.kind, message = %record.message)
+            };
+        }
+
+        let level = match record.kind {
+            RecordKind::Error => tracing::Level::ERROR,
+            _ => self.level,
+        };
+        match level {
+            tracing::Level::ERROR => emit!(tracing::Level::ERROR),
+            tracing::Level::WARN => emit!(tracing::Level::WARN),
+            tracing::Level::INFO => emit!(tracing::Level::INFO),
+            tracing::Level::DEBUG => emit!(tracing::Level::DEBUG),
+            tracing::Level::TRACE => emit!(tracing::Level::TRACE),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Logger for Box<TracingLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// StructuredLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that passes log records to the [`log`] facade along with structured key-value pairs.
+///
+/// Unlike [`ConsoleLogger`], which formats everything into a single line, this implementation of the [`Logger`]
+/// trait attaches log record kind, number of transferred bytes and message as `kind`, `bytes` and `message`
+/// key-value pairs, so backends which support them, such as `tracing-log`, can capture them as separate fields.
+/// Line in `{kind} {message}` format is still passed as message of [`log::Record`] for backends which don't.
+/// Log records with the [`Error`] kind are always emitted with [`log::Level::Error`]. It is available only when
+/// `log-kv` feature is enabled.
+///
+/// [`Error`]: RecordKind::Error
+#[cfg(feature = "log-kv")]
+#[derive(Debug, Clone)]
+pub struct StructuredLogger {
+    level: log::Level,
+}
+
+#[cfg(feature = "log-kv")]
+impl StructuredLogger {
+    /// Construct a new instance of [`StructuredLogger`] using provided log level [`str`]. Returns an [`Err`] in
+    /// case if provided log level [`str`] was incorrect.
+    pub fn new(level: &str) -> Result<Self, log::ParseLevelError> {
+        let level = log::Level::from_str(level)?;
+        Ok(Self { level })
+    }
+
+    /// Construct a new instance of [`StructuredLogger`] using provided log level [`str`]. Panics in case if
+    /// provided log level [`str`] was incorrect.
+    pub fn new_unchecked(level: &str) -> Self {
+        Self::new(level).unwrap()
+    }
+}
+
+#[cfg(feature = "log-kv")]
+impl Logger for StructuredLogger {
+    fn log(&mut self, record: Record) {
+        let level = match record.kind {
+            RecordKind::Error => log::Level::Error,
+            _ => self.level,
+        };
+        log::log!(
+            level,
+            kind = record.kind.name(),
+            bytes = record.byte_count,
+            message = record.message.as_str();
+            "{} {}",
+            record.kind,
+            record.message
+        );
+    }
+}
+
+#[cfg(feature = "log-kv")]
+impl Logger for Box<StructuredLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SyslogLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that sends log records to syslog daemon.
+///
+/// This implementation of the [`Logger`] trait sends each log record ([`Record`]) to syslog daemon in RFC 3164
+/// format using the provided [`syslog::Severity`]. Log records with the [`Error`] kind ignore the provided
+/// [`syslog::Severity`] and are always sent with [`syslog::Severity::LOG_ERR`]. It is available only when
+/// `syslog` feature is enabled.
+///
+/// [`Error`]: crate::RecordKind::Error
+#[cfg(feature = "syslog")]
+pub struct SyslogLogger<W: io::Write + Send + 'static = syslog::LoggerBackend> {
+    logger: syslog::Logger<W, syslog::Formatter3164>,
+    severity: syslog::Severity,
+}
+
+#[cfg(feature = "syslog")]
+impl SyslogLogger {
+    /// Construct a new instance of [`SyslogLogger`] connected to the local syslog daemon socket using provided
+    /// severity. Log records are sent with `user` facility on behalf of the current process. Returns an [`Err`]
+    /// in case if connection to the socket failed.
+    pub fn new(severity: syslog::Severity) -> Result<Self, syslog::Error> {
+        let logger = syslog::unix(syslog::Formatter3164::default())?;
+        Ok(Self::from_logger(logger, severity))
+    }
+}
+
+#[cfg(feature = "syslog")]
+impl<W: io::Write + Send + 'static> SyslogLogger<W> {
+    /// Construct a new instance of [`SyslogLogger`] using provided [`syslog::Logger`] and severity. Useful when
+    /// syslog daemon is reachable through UDP or TCP, or when custom facility is required.
+    pub fn from_logger(
+        logger: syslog::Logger<W, syslog::Formatter3164>,
+        severity: syslog::Severity,
+    ) -> Self {
+        Self { logger, severity }
+    }
+}
+
+#[cfg(feature = "syslog")]
+impl<W: io::Write + Send + 'static> fmt::Debug for SyslogLogger<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyslogLogger")
+            .field("formatter", &self.logger.formatter)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "syslog")]
+impl<W: io::Write + Send + 'static> Logger for SyslogLogger<W> {
+    fn log(&mut self, record: Record) {
+        use syslog::LogFormat;
+
+        let severity = match record.kind {
+            RecordKind::Error => syslog::Severity::LOG_ERR,
+            _ => self.severity,
+        };
+        let message = format!("{} {}", record.kind, record.message);
+        let _ = self
+            .logger
+            .formatter
+            .format(&mut self.logger.backend, severity, message);
+        let _ = self.logger.backend.flush();
+    }
+}
+
+#[cfg(feature = "syslog")]
+impl<W: io::Write + Send + 'static> Logger for Box<SyslogLogger<W>> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SqliteLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "rusqlite")]
+const SQLITE_INSERT: &str = "INSERT INTO records (time, kind, message) VALUES (?1, ?2, ?3)";
+
+/// Logger implementation that inserts log records into SQLite database.
+///
+/// This implementation of the [`Logger`] trait creates `records(time TEXT, kind TEXT, message TEXT)` table during
+/// construction, unless it already exists, and inserts each log record ([`Record`]) into it as a separate row.
+/// Timestamp is stored in RFC 3339 format and kind as its lowercase name, for example `read`. Insert statement is
+/// prepared once and kept in statement cache of the owned connection, so it is not compiled again for every log
+/// record. Failed insertions are counted, see [`failed_count`] method, and the first of them is reported using
+/// [`log::warn!`] macro, so logging never interrupts IO. It is available only when `rusqlite` feature is enabled.
+///
+/// [`failed_count`]: SqliteLogger::failed_count
+#[cfg(feature = "rusqlite")]
+#[derive(Debug)]
+pub struct SqliteLogger {
+    connection: rusqlite::Connection,
+    failed_count: usize,
+}
+
+#[cfg(feature = "rusqlite")]
+impl SqliteLogger {
+    /// Construct a new instance of [`SqliteLogger`] using provided connection. Creates `records` table if it
+    /// doesn't exist and prepares insert statement. Returns an [`Err`] in case if any of them failed.
+    pub fn new(connection: rusqlite::Connection) -> rusqlite::Result<Self> {
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS records (time TEXT, kind TEXT, message TEXT)",
+            (),
+        )?;
+        connection.prepare_cached(SQLITE_INSERT)?;
+        Ok(Self {
+            connection,
+            failed_count: 0,
+        })
+    }
+
+    /// Construct a new instance of [`SqliteLogger`] which writes into SQLite database at provided path, the
+    /// database file is created if it doesn't exist.
+    pub fn open<P: AsRef<path::Path>>(path: P) -> rusqlite::Result<Self> {
+        Self::new(rusqlite::Connection::open(path)?)
+    }
+
+    /// Returns a shared reference to the underlying connection, for example to query inserted log records.
+    #[inline]
+    pub fn connection(&self) -> &rusqlite::Connection {
+        &self.connection
+    }
+
+    /// Retrieve the number of log records which were not inserted because of database error.
+    #[inline]
+    pub fn failed_count(&self) -> usize {
+        self.failed_count
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl Logger for SqliteLogger {
+    fn log(&mut self, record: Record) {
+        let time = record
+            .time
+            .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true);
+        let result = self
+            .connection
+            .prepare_cached(SQLITE_INSERT)
+            .and_then(|mut statement| {
+                statement.execute((time, record.kind.name(), record.message))
+            });
+        if let Err(e) = result {
+            if self.failed_count == 0 {
+                log::warn!("Failed to insert log record into SQLite database: {e}");
+            }
+            self.failed_count += 1;
+        }
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl Logger for Box<SqliteLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// BackgroundLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`Logger`] trait passes log records ([`Record`]) to an inner logger running inside a
+/// dedicated background thread.
+///
+/// Log records are sent to the thread through an unbounded channel, so [`log`] method only sends and IO
+/// operations of [`LoggedStream`] are not slowed down by slow logging parts, such as [`FileLogger`] or a
+/// database. Unlike `SpawnedLogger`, it doesn't require [`tokio`] runtime and works with any synchronous
+/// [`Logger`] implementation. When [`BackgroundLogger`] is dropped, the thread processes all remaining log
+/// records and is joined.
+///
+/// [`log`]: Logger::log
+/// [`LoggedStream`]: crate::LoggedStream
+#[derive(Debug)]
+pub struct BackgroundLogger {
+    sender: Option<mpsc::Sender<Record>>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl BackgroundLogger {
+    /// Construct a new instance of [`BackgroundLogger`] spawning a thread which passes log records to provided
+    /// logger.
+    pub fn new<L: Logger>(mut logger: L) -> Self {
+        let (sender, receiver) = mpsc::channel::<Record>();
+        let handle = thread::spawn(move || {
+            for record in receiver {
+                logger.log(record);
+            }
+        });
+        Self {
+            sender: Some(sender),
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Logger for BackgroundLogger {
+    fn log(&mut self, record: Record) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(record);
+        }
+    }
+}
+
+impl Logger for Box<BackgroundLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+impl Drop for BackgroundLogger {
+    fn drop(&mut self) {
+        // Closing the channel lets the thread finish once all remaining log records are processed.
+        drop(self.sender.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AsyncLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Trait for asynchronous processing of log records.
+///
+/// This trait is an asynchronous analogue of [`Logger`] trait intended for loggers which have to wait for IO,
+/// for example [`TokioFileLogger`]. Since [`LoggedStream`] emits log records from synchronous methods, including
+/// `poll_*` methods of [`AsyncRead`] and [`AsyncWrite`] traits, the future returned by [`log`] method can't be
+/// awaited there directly. Implementation of this trait should be wrapped into [`SpawnedLogger`], which awaits
+/// log records processing inside a separate [`tokio`] task. It is available only when `async-logger` feature
+/// is enabled.
+///
+/// [`log`]: AsyncLogger::log
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`AsyncRead`]: tokio::io::AsyncRead
+/// [`AsyncWrite`]: tokio::io::AsyncWrite
+#[cfg(feature = "async-logger")]
+pub trait AsyncLogger: Send + 'static {
+    fn log(&mut self, record: Record) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+#[cfg(feature = "async-logger")]
+impl AsyncLogger for Box<dyn AsyncLogger> {
+    fn log(&mut self, record: Record) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        (**self).log(record)
+    }
+}
+
+/// This implementation of [`Logger`] trait passes log records ([`Record`]) to the provided [`AsyncLogger`]
+/// implementation running inside a separate [`tokio`] task.
+///
+/// Log records are sent to the task through an unbounded channel, so [`log`] method never blocks the caller.
+/// The task processes log records one by one in the order they were logged and finishes after [`SpawnedLogger`]
+/// is dropped and all remaining log records are processed. It is available only when `async-logger` feature
+/// is enabled.
+///
+/// [`log`]: Logger::log
+#[cfg(feature = "async-logger")]
+#[derive(Debug, Clone)]
+pub struct SpawnedLogger {
+    sender: tokio::sync::mpsc::UnboundedSender<Record>,
+}
+
+#[cfg(feature = "async-logger")]
+impl SpawnedLogger {
+    /// Construct a new instance of [`SpawnedLogger`] spawning a task which processes log records using provided
+    /// [`AsyncLogger`] implementation. Panics in case if called outside of [`tokio`] runtime.
+    pub fn new<L: AsyncLogger>(mut logger: L) -> Self {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<Record>();
+        tokio::spawn(async move {
+            while let Some(record) = receiver.recv().await {
+                logger.log(record).await;
+            }
+        });
+        Self { sender }
+    }
+}
+
+#[cfg(feature = "async-logger")]
+impl Logger for SpawnedLogger {
+    fn log(&mut self, record: Record) {
+        let _ = self.sender.send(record);
+    }
+}
+
+#[cfg(feature = "async-logger")]
+impl Logger for Box<SpawnedLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TokioFileLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// This implementation of [`AsyncLogger`] trait writes log records ([`Record`]) into provided [`tokio`] file.
+///
+/// It is an asynchronous analogue of [`FileLogger`] which doesn't block [`tokio`] runtime worker threads while
+/// writing. Each log record is flushed right after it was written. Log record timestamp is written in RFC 3339
+/// format by default, which can be changed to any [`strftime`] pattern using [`with_time_format`] method. It is
+/// available only when `async-logger` feature is enabled.
+///
+/// [`with_time_format`]: TokioFileLogger::with_time_format
+/// [`strftime`]: chrono::format::strftime
+#[cfg(feature = "async-logger")]
+pub struct TokioFileLogger {
+    file: tokio::fs::File,
+    time_format: String,
+}
+
+#[cfg(feature = "async-logger")]
+impl TokioFileLogger {
+    /// Construct a new instance of [`TokioFileLogger`] using provided file.
+    pub fn new(file: tokio::fs::File) -> Self {
+        Self {
+            file,
+            time_format: String::from(DEFAULT_TIME_FORMAT),
+        }
+    }
+
+    /// Construct a new instance of [`TokioFileLogger`] by opening a file located at provided path. The file is
+    /// created if it does not exist. In case if `append` is `true`, new log records are appended to the end of the
+    /// existing file, otherwise the file is truncated. Returns an [`Err`] in case if the file can't be opened.
+    pub async fn open<P: AsRef<path::Path>>(path: P, append: bool) -> io::Result<Self> {
+        let mut options = tokio::fs::OpenOptions::new();
+        options.create(true);
+        if append {
+            options.append(true);
+        } else {
+            options.write(true).truncate(true);
+        }
+        Self::open_with_options(path, &options).await
+    }
+
+    /// Construct a new instance of [`TokioFileLogger`] by opening a file located at provided path using provided
+    /// [`tokio::fs::OpenOptions`], for example to fail if the file already exists. Returns an [`Err`] in case if
+    /// the file can't be opened.
+    pub async fn open_with_options<P: AsRef<path::Path>>(
+        path: P,
+        options: &tokio::fs::OpenOptions,
+    ) -> io::Result<Self> {
+        Ok(Self::new(options.open(path).await?))
+    }
+
+    /// Set [`strftime`] pattern used to format log record timestamp. It is `%+` (RFC 3339) by default. In case if
+    /// provided pattern is invalid, the default one is kept, so logging never panics because of it.
+    ///
+    /// [`strftime`]: chrono::format::strftime
+    pub fn with_time_format(mut self, time_format: String) -> Self {
+        if let Some(time_format) = validated_time_format(time_format) {
+            self.time_format = time_format;
+        }
+        self
+    }
+}
+
+#[cfg(feature = "async-logger")]
+impl AsyncLogger for TokioFileLogger {
+    fn log(&mut self, record: Record) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        use tokio::io::AsyncWriteExt;
+
+        let line = format!(
+            "[{}] {} {}\n",
+            record.time.format(&self.time_format),
+            record.kind,
+            record.message
+        );
+        Box::pin(async move {
+            let _ = self.file.write_all(line.as_bytes()).await;
+            let _ = self.file.flush().await;
+        })
+    }
+}
+
+#[cfg(feature = "async-logger")]
+impl AsyncLogger for Box<TokioFileLogger> {
+    fn log(&mut self, record: Record) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MultiLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that forwards log records to multiple inner loggers.
+///
+/// This implementation of the [`Logger`] trait passes each log record ([`Record`]) to every inner logger in
+/// the order they were added, so one [`LoggedStream`] can simultaneously write log records, for example, to the
+/// console and to the file.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+#[derive(Default)]
+pub struct MultiLogger {
+    loggers: Vec<Box<dyn Logger>>,
+}
+
+impl MultiLogger {
+    /// Construct a new instance of [`MultiLogger`] using provided inner loggers.
+    pub fn new(loggers: Vec<Box<dyn Logger>>) -> Self {
+        Self { loggers }
+    }
+
+    /// Add provided logger to the end of inner loggers list.
+    pub fn push<L: Logger>(mut self, logger: L) -> Self {
+        self.loggers.push(Box::new(logger));
+        self
+    }
+}
+
+impl Logger for MultiLogger {
+    fn log(&mut self, record: Record) {
+        if let Some((last, rest)) = self.loggers.split_last_mut() {
+            for logger in rest {
+                logger.log(record.clone());
+            }
+            last.log(record);
+        }
+    }
+}
+
+impl Logger for Box<MultiLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SamplingLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that forwards only every Nth log record to an inner logger.
+///
+/// This implementation of the [`Logger`] trait counts received log records ([`Record`]) and passes the first one
+/// and then every Nth one to the inner logger, while the rest are dropped. It is useful for very busy streams
+/// where representative samples are enough. In case if provided N is `0` or `1`, every log record is forwarded.
+#[derive(Debug)]
+pub struct SamplingLogger<L: Logger> {
+    inner: L,
+    every: usize,
+    counter: usize,
+}
+
+impl<L: Logger> SamplingLogger<L> {
+    /// Construct a new instance of [`SamplingLogger`] using provided inner logger and sampling interval.
+    pub fn new(inner: L, every: usize) -> Self {
+        Self {
+            inner,
+            every: every.max(1),
+            counter: 0,
+        }
+    }
+
+    /// Retrieve a reference to the inner logger.
+    #[inline]
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger> Logger for SamplingLogger<L> {
+    fn log(&mut self, record: Record) {
+        let index = self.counter;
+        self.counter = self.counter.wrapping_add(1);
+        if index % self.every == 0 {
+            self.inner.log(record);
+        }
+    }
+}
+
+impl<L: Logger> Logger for Box<SamplingLogger<L>> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MapLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that transforms log records using provided closure before passing them to an inner
+/// logger.
+///
+/// This implementation of the [`Logger`] trait applies the closure to every received log record ([`Record`]) and
+/// forwards the returned one, so records can be redacted, for example to hide passwords, enriched or reformatted
+/// right before they reach the sink.
+pub struct MapLogger<L: Logger> {
+    inner: L,
+    map: Box<dyn FnMut(Record) -> Record + Send>,
+}
+
+impl<L: Logger> MapLogger<L> {
+    /// Construct a new instance of [`MapLogger`] using provided inner logger and log record transforming closure.
+    pub fn new<F>(inner: L, map: F) -> Self
+    where
+        F: FnMut(Record) -> Record + Send + 'static,
+    {
+        Self {
+            inner,
+            map: Box::new(map),
+        }
+    }
+
+    /// Retrieve a reference to the inner logger.
+    #[inline]
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+}
+
+impl<L: Logger + fmt::Debug> fmt::Debug for MapLogger<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapLogger")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: Logger> Logger for MapLogger<L> {
+    #[inline]
+    fn log(&mut self, record: Record) {
+        let record = (self.map)(record);
+        self.inner.log(record)
+    }
+}
+
+impl<L: Logger> Logger for Box<MapLogger<L>> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FnLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that passes log records to closure provided during construction.
+///
+/// It allows to quickly integrate log records ([`Record`]) into custom data structures without implementing
+/// [`Logger`] trait for a new structure manually.
+pub struct FnLogger {
+    log: Box<dyn FnMut(Record) + Send>,
+}
+
+impl FnLogger {
+    /// Construct a new instance of [`FnLogger`] using provided log record processing closure.
+    pub fn new<F>(log: F) -> Self
+    where
+        F: FnMut(Record) + Send + 'static,
+    {
+        Self { log: Box::new(log) }
+    }
+}
+
+impl fmt::Debug for FnLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnLogger").finish_non_exhaustive()
+    }
+}
+
+impl Logger for FnLogger {
+    #[inline]
+    fn log(&mut self, record: Record) {
+        (self.log)(record)
+    }
+}
+
+impl Logger for Box<FnLogger> {
+    fn log(&mut self, record: Record) {
+        (**self).log(record)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// NullLogger
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Logger implementation that discards all log records.
+///
+/// This implementation of the [`Logger`] trait does nothing with received log records ([`Record`]). It allows to
+/// disable logging without changing the structure of [`LoggedStream`] type.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullLogger;
+
+impl Logger for NullLogger {
+    #[inline]
+    fn log(&mut self, _record: Record) {}
+}
+
+impl Logger for Box<NullLogger> {
+    #[inline]
+    fn log(&mut self, _record: Record) {}
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer_formatter::BufferFormatter;
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::buffer_formatter::TruncatingFormatter;
+    use crate::logger::BackgroundLogger;
+    use crate::logger::ByteBudgetLogger;
+    use crate::logger::ChannelLogger;
+    use crate::logger::ColorMode;
+    use crate::logger::ConsoleLogger;
+    use crate::logger::ConsoleTarget;
+    use crate::logger::FileLogger;
+    use crate::logger::FnLogger;
+    use crate::logger::Logger;
+    use crate::logger::MapLogger;
+    use crate::logger::MemoryStorageLogger;
+    use crate::logger::MultiLogger;
+    use crate::logger::NullLogger;
+    use crate::logger::RollingFileLogger;
+    use crate::logger::SamplingLogger;
+    use crate::logger::SharedMemoryStorageLogger;
+    use crate::logger::WriteLogger;
+    use crate::record::Record;
+    use crate::record::RecordKind;
+    use std::fs;
+
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn test_unpin() {
+        assert_unpin::<ConsoleLogger>();
+        assert_unpin::<ChannelLogger>();
+        assert_unpin::<MemoryStorageLogger>();
+        assert_unpin::<SharedMemoryStorageLogger>();
+        assert_unpin::<ByteBudgetLogger>();
+        assert_unpin::<BackgroundLogger>();
+        assert_unpin::<FileLogger>();
+        assert_unpin::<WriteLogger<Vec<u8>>>();
+        assert_unpin::<RollingFileLogger>();
+        assert_unpin::<MultiLogger>();
+        assert_unpin::<NullLogger>();
+        assert_unpin::<FnLogger>();
+        assert_unpin::<SamplingLogger<NullLogger>>();
+        assert_unpin::<MapLogger<NullLogger>>();
+    }
+
+    #[test]
+    fn test_trait_object_safety() {
+        // Assert traint object construct.
+        let mut console: Box<dyn Logger> = Box::new(ConsoleLogger::new_unchecked("debug"));
+        let mut memory: Box<dyn Logger> = Box::new(MemoryStorageLogger::new(100));
+        let mut shared: Box<dyn Logger> = Box::new(SharedMemoryStorageLogger::new(100));
+        let mut budget: Box<dyn Logger> = Box::new(ByteBudgetLogger::new(100));
+        let mut channel: Box<dyn Logger> = Box::new(ChannelLogger::new());
+        let mut multi: Box<dyn Logger> =
+            Box::new(MultiLogger::default().push(MemoryStorageLogger::new(100)));
+        let mut null: Box<dyn Logger> = Box::new(NullLogger);
+
+        let record = Record::new(RecordKind::Open, String::from("test log record"));
+
+        // Assert that trait object methods are dispatchable.
+        console.log(record.clone());
+        memory.log(record.clone());
+        shared.log(record.clone());
+        budget.log(record.clone());
+        channel.log(record.clone());
+        multi.log(record.clone());
+        null.log(record);
+    }
+
+    const CHILD_ENV: &str = "LOGGED_STREAM_CONSOLE_LOGGER_CHILD";
+
+    /// Runs provided test in a child process with provided environment variables and returns its captured
+    /// standard error stream. Inside the child process [`CHILD_ENV`] environment variable is set.
+    fn run_child_test(name: &str, envs: &[(&str, &str)]) -> String {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", name, "--nocapture"])
+            .env(CHILD_ENV, "1")
+            .env_remove("NO_COLOR")
+            .envs(envs.iter().copied())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stderr).unwrap()
+    }
+
+    #[test]
+    fn test_console_logger_stderr() {
+        // Child process writes log record into its standard error stream, which is captured by parent process.
+        if std::env::var_os(CHILD_ENV).is_some() {
+            let mut logger =
+                ConsoleLogger::new_unchecked("debug").with_target(ConsoleTarget::Stderr);
+            logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+            return;
+        }
+
+        let stderr = run_child_test("logger::tests::test_console_logger_stderr", &[]);
+        assert!(stderr.lines().any(|line| line == "> 01:02:03"));
+    }
+
+    #[test]
+    fn test_console_logger_color() {
+        let logger = ConsoleLogger::new_unchecked("debug").with_color(ColorMode::Always);
+        assert_eq!(
+            logger.format_record(&Record::new(RecordKind::Read, String::from("01:02:03"))),
+            "\x1b[32m< 01:02:03\x1b[0m"
+        );
+        assert_eq!(
+            logger.format_record(&Record::new(RecordKind::Error, String::from("failure"))),
+            "\x1b[31m! failure\x1b[0m"
+        );
+
+        let logger = ConsoleLogger::new_unchecked("debug").with_color(ColorMode::Never);
+        assert_eq!(
+            logger.format_record(&Record::new(RecordKind::Read, String::from("01:02:03"))),
+            "< 01:02:03"
+        );
+    }
+
+    #[test]
+    fn test_console_logger_timestamp() {
+        let record = Record::new(RecordKind::Read, String::from("01:02:03"));
+
+        let logger = ConsoleLogger::new_unchecked("debug").with_timestamp(true);
+        let line = logger.format_record(&record);
+        let (time, rest) = line.split_once(' ').unwrap();
+        assert_eq!(rest, "< 01:02:03");
+        assert_eq!(
+            chrono::DateTime::parse_from_rfc3339(time).unwrap(),
+            record.time
+        );
+
+        let logger = ConsoleLogger::new_unchecked("debug");
+        assert_eq!(logger.format_record(&record), "< 01:02:03");
+    }
+
+    #[test]
+    fn test_console_logger_prefix() {
+        let record = Record::new(RecordKind::Write, String::from("01:02:03"));
+
+        let logger = ConsoleLogger::new_unchecked("debug").with_prefix(String::from("[conn-7]"));
+        assert_eq!(logger.format_record(&record), "[conn-7] > 01:02:03");
+
+        let logger = logger.with_timestamp(true);
+        let line = logger.format_record(&record);
+        assert!(line.starts_with("[conn-7] "));
+        assert!(line.ends_with(" > 01:02:03"));
+
+        let logger = ConsoleLogger::new_unchecked("debug")
+            .with_prefix(String::from("[conn-7]"))
+            .with_color(ColorMode::Always);
+        assert_eq!(
+            logger.format_record(&record),
+            "[conn-7] \x1b[34m> 01:02:03\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_console_logger_byte_count() {
+        let buffer = [0x0a; 100];
+        let formatter = TruncatingFormatter::new(HexadecimalFormatter::new_default(), Some(2));
+        let record = Record::with_byte_count(
+            RecordKind::Read,
+            formatter.format_buffer(&buffer),
+            buffer.len(),
+        );
+        assert_eq!(record.byte_count, Some(100));
+
+        let logger = ConsoleLogger::new_unchecked("debug").with_byte_count(true);
+        assert_eq!(
+            logger.format_record(&record),
+            "< 0a:0a … (+98 more bytes) (100 bytes)"
+        );
+        assert_eq!(
+            logger.format_record(&Record::new(RecordKind::Drop, String::from("Deallocated."))),
+            "x Deallocated."
+        );
+
+        let logger = ConsoleLogger::new_unchecked("debug");
+        assert_eq!(logger.format_record(&record), "< 0a:0a … (+98 more bytes)");
+    }
+
+    #[test]
+    fn test_console_logger_wrap() {
+        let record =
+            Record::with_byte_count(RecordKind::Read, String::from("01:02:03:04:05:06:07:08"), 8);
+
+        let logger = ConsoleLogger::new_unchecked("debug").with_wrap(8);
+        assert_eq!(
+            logger.format_lines(&record),
+            ["< 01:02:03", "  04:05:06", "  07:08"]
+        );
+
+        let logger = logger
+            .with_prefix(String::from("[conn-7]"))
+            .with_byte_count(true);
+        assert_eq!(
+            logger.format_lines(&record),
+            [
+                "[conn-7] < 01:02:03",
+                "[conn-7]   04:05:06",
+                "[conn-7]   07:08 (8 bytes)"
+            ]
+        );
+
+        // Message without separators is split exactly at provided width.
+        let record = Record::new(RecordKind::Write, String::from("0123456789abcdef"));
+        let logger = ConsoleLogger::new_unchecked("debug").with_wrap(5);
+        assert_eq!(
+            logger.format_lines(&record),
+            ["> 01234", "  56789", "  abcde", "  f"]
+        );
+
+        let logger = ConsoleLogger::new_unchecked("debug").with_wrap(100);
+        assert_eq!(logger.format_lines(&record), ["> 0123456789abcdef"]);
+        let logger = ConsoleLogger::new_unchecked("debug");
+        assert_eq!(logger.format_lines(&record), ["> 0123456789abcdef"]);
+        assert_eq!(
+            logger.format_lines(&Record::new(RecordKind::Write, String::new())),
+            ["> "]
+        );
+    }
+
+    /// Log record passed to the `log` facade: its level, message and key-value pairs.
+    type Captured = (log::Level, String, Vec<(String, String)>);
+
+    static CAPTURED: std::sync::Mutex<Vec<Captured>> = std::sync::Mutex::new(Vec::new());
+
+    struct CapturingLogger;
+
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            #[allow(unused_mut)]
+            let mut key_values = Vec::new();
+            #[cfg(feature = "log-kv")]
+            {
+                struct Collect<'a>(&'a mut Vec<(String, String)>);
+
+                impl<'kvs> log::kv::VisitSource<'kvs> for Collect<'_> {
+                    fn visit_pair(
+                        &mut self,
+                        key: log::kv::Key<'kvs>,
+                        value: log::kv::Value<'kvs>,
+                    ) -> Result<(), log::kv::Error> {
+                        self.0.push((key.to_string(), value.to_string()));
+                        Ok(())
+                    }
+                }
+
+                record
+                    .key_values()
+                    .visit(&mut Collect(&mut key_values))
+                    .unwrap();
+            }
+            let message = record.args().to_string();
+            CAPTURED
+                .lock()
+                .unwrap()
+                .push((record.level(), message, key_values));
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Returns log records passed to the `log` facade which message contains provided marker. Logger can be
+    /// installed only once per process and other tests may pass log records to the facade concurrently, so
+    /// tests tell their own log records apart by unique marker.
+    fn captured_with_marker(marker: &str) -> Vec<Captured> {
+        CAPTURED
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, message, _)| message.contains(marker))
+            .cloned()
+            .collect()
+    }
+
+    fn install_capturing_logger() {
+        static INSTALL: std::sync::Once = std::sync::Once::new();
+        INSTALL.call_once(|| {
+            log::set_logger(&CapturingLogger).unwrap();
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+    }
+
+    #[test]
+    fn test_console_logger_level_for() {
+        install_capturing_logger();
+
+        let mut logger = ConsoleLogger::new_unchecked("info")
+            .with_prefix(String::from("[level-for]"))
+            .with_level_for(RecordKind::Read, log::Level::Debug)
+            .with_level_for(RecordKind::Shutdown, log::Level::Warn);
+        logger.log(Record::new(RecordKind::Read, String::from("01")));
+        logger.log(Record::new(RecordKind::Write, String::from("02")));
+        logger.log(Record::new(RecordKind::Shutdown, String::from("Shutdown.")));
+        logger.log(Record::new(RecordKind::Error, String::from("failure")));
+
+        let mut logger = logger.with_level_for(RecordKind::Error, log::Level::Warn);
+        logger.log(Record::new(RecordKind::Error, String::from("recoverable")));
+
+        let captured: Vec<(log::Level, String)> = captured_with_marker("[level-for] ")
+            .into_iter()
+            .map(|(level, message, _)| (level, message))
+            .collect();
+        assert_eq!(
+            captured,
+            vec![
+                (log::Level::Debug, String::from("[level-for] < 01")),
+                (log::Level::Info, String::from("[level-for] > 02")),
+                (log::Level::Warn, String::from("[level-for] - Shutdown.")),
+                (log::Level::Error, String::from("[level-for] ! failure")),
+                (log::Level::Warn, String::from("[level-for] ! recoverable")),
+            ]
+        );
+    }
+
+    #[cfg(feature = "log-kv")]
+    #[test]
+    fn test_structured_logger() {
+        use crate::logger::StructuredLogger;
+
+        install_capturing_logger();
+
+        let mut logger = StructuredLogger::new_unchecked("debug");
+        logger.log(Record::with_byte_count(
+            RecordKind::Write,
+            String::from("[structured] 01:02:03"),
+            3,
+        ));
+        logger.log(Record::new(
+            RecordKind::Error,
+            String::from("[structured] failure"),
+        ));
+
+        let captured = captured_with_marker("[structured] ");
+        let pairs = |kind: &str, bytes: &str, message: &str| {
+            vec![
+                (String::from("kind"), String::from(kind)),
+                (String::from("bytes"), String::from(bytes)),
+                (String::from("message"), String::from(message)),
+            ]
+        };
+        assert_eq!(
+            captured,
+            vec![
+                (
+                    log::Level::Debug,
+                    String::from("> [structured] 01:02:03"),
+                    pairs("write", "3", "[structured] 01:02:03")
+                ),
+                (
+                    log::Level::Error,
+                    String::from("! [structured] failure"),
+                    pairs("error", "None", "[structured] failure")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_console_logger_no_color() {
+        if std::env::var_os(CHILD_ENV).is_some() {
+            let mut logger = ConsoleLogger::new_unchecked("debug")
+                .with_target(ConsoleTarget::Stderr)
+                .with_color(ColorMode::Auto);
+            logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+            return;
+        }
+
+        let name = "logger::tests::test_console_logger_no_color";
+        let stderr = run_child_test(name, &[("NO_COLOR", "1")]);
+        assert!(stderr.lines().any(|line| line == "> 01:02:03"));
+
+        let stderr = run_child_test(name, &[]);
+        assert!(stderr
+            .lines()
+            .any(|line| line == "\x1b[34m> 01:02:03\x1b[0m"));
+    }
+
+    #[test]
+    fn test_memory_storage_logger_max_length() {
+        let mut capped = MemoryStorageLogger::new(2);
+        let mut nothing = MemoryStorageLogger::new(0);
+        let mut unbounded = MemoryStorageLogger::new_unbounded();
+
+        for index in 0..1000 {
+            let record = Record::new(RecordKind::Read, format!("{index}"));
+            capped.log(record.clone());
+            nothing.log(record.clone());
+            unbounded.log(record);
+        }
+
+        let records = capped.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, String::from("998"));
+        assert_eq!(records[1].message, String::from("999"));
+
+        assert!(nothing.is_empty());
+
+        let records = unbounded.get_log_records();
+        assert_eq!(records.len(), 1000);
+        assert_eq!(records[0].message, String::from("0"));
+        assert_eq!(records[999].message, String::from("999"));
+    }
+
+    #[test]
+    fn test_memory_storage_logger_drain() {
+        let mut logger = MemoryStorageLogger::new(100);
+        assert!(logger.is_empty());
+
+        logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        logger.log(Record::new(RecordKind::Read, String::from("04:05:06")));
+        assert_eq!(logger.len(), 2);
+        assert!(!logger.is_empty());
+
+        let records = logger.drain_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[1].kind, RecordKind::Read);
+        assert!(logger.is_empty());
+        assert!(logger.get_log_records().is_empty());
+        assert!(logger.drain_log_records().is_empty());
+
+        // Storage still works after draining.
+        logger.log(Record::new(RecordKind::Drop, String::from("deallocated")));
+        assert_eq!(logger.len(), 1);
+    }
+
+    #[test]
+    fn test_memory_storage_logger_replay_into() {
+        let mut source = MemoryStorageLogger::new(100);
+        source.log(Record::new(RecordKind::Open, String::from("opened")));
+        source.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        source.log(Record::new(RecordKind::Read, String::from("04:05:06")));
+
+        let mut target = MemoryStorageLogger::new(100);
+        target.log(Record::new(RecordKind::Error, String::from("failure")));
+        source.replay_into(&mut target);
+
+        let source_records = source.get_log_records();
+        let target_records = target.get_log_records();
+        assert_eq!(source_records.len(), 3);
+        assert_eq!(target_records.len(), 4);
+        assert_eq!(target_records[0].kind, RecordKind::Error);
+        assert!(target_records.iter().skip(1).eq(source_records.iter()));
+
+        let mut boxed: Box<dyn Logger> = Box::new(MemoryStorageLogger::new(100));
+        source.replay_into(&mut boxed);
+    }
+
+    #[test]
+    fn test_bounded_channel_logger() {
+        let mut logger = ChannelLogger::bounded(2);
+        let receiver = logger.take_receiver_unchecked();
+
+        for index in 0..5 {
+            logger.log(Record::new(RecordKind::Read, format!("{index}")));
+        }
+        assert_eq!(logger.dropped_count(), 3);
+
+        assert_eq!(receiver.try_recv().unwrap().message, String::from("0"));
+        assert_eq!(receiver.try_recv().unwrap().message, String::from("1"));
+        assert!(receiver.try_recv().is_err());
+
+        // Buffer has space again after draining.
+        logger.log(Record::new(RecordKind::Write, String::from("5")));
+        assert_eq!(logger.dropped_count(), 3);
+        assert_eq!(receiver.try_recv().unwrap().message, String::from("5"));
+
+        // Unbounded channel never drops log records.
+        let mut logger = ChannelLogger::new();
+        let _receiver = logger.take_receiver_unchecked();
+        for index in 0..1000 {
+            logger.log(Record::new(RecordKind::Read, format!("{index}")));
+        }
+        assert_eq!(logger.dropped_count(), 0);
+    }
+
+    #[test]
+    fn test_channel_logger_subscribe() {
+        let mut logger = ChannelLogger::new();
+        let first = logger.subscribe();
+        let second = logger.subscribe();
+
+        let record = Record::new(RecordKind::Write, String::from("01:02:03"));
+        logger.log(record.clone());
+        assert_eq!(first.try_recv().unwrap(), record);
+        assert_eq!(second.try_recv().unwrap(), record);
+
+        // Receiver which was taken from logger receives the same log records.
+        let main = logger.take_receiver_unchecked();
+        assert_eq!(main.try_recv().unwrap(), record);
+        let record = Record::new(RecordKind::Read, String::from("04:05:06"));
+        logger.log(record.clone());
+        assert_eq!(main.try_recv().unwrap(), record);
+        assert_eq!(first.try_recv().unwrap(), record);
+        assert_eq!(second.try_recv().unwrap(), record);
+
+        // Channels with dropped receivers are pruned.
+        drop(first);
+        drop(main);
+        logger.log(record.clone());
+        assert_eq!(logger.senders.len(), 1);
+        assert_eq!(second.try_recv().unwrap(), record);
+    }
+
+    #[test]
+    fn test_shared_memory_storage_logger() {
+        let reader = SharedMemoryStorageLogger::new(100);
+        let mut writer = reader.clone();
+
+        let handle = std::thread::spawn(move || {
+            for index in 0..50u8 {
+                writer.log(Record::new(RecordKind::Write, format!("{index:02x}")));
+            }
+        });
+        // Records are read concurrently, so any snapshot must contain a prefix of logged ones.
+        while !handle.is_finished() {
+            let snapshot = reader.snapshot();
+            for (index, record) in snapshot.iter().enumerate() {
+                assert_eq!(record.message, format!("{index:02x}"));
+            }
+        }
+        handle.join().unwrap();
+
+        assert_eq!(reader.len(), 50);
+        assert_eq!(reader.snapshot()[49].message, "31");
+        assert_eq!(reader.drain_log_records().len(), 50);
+        assert!(reader.is_empty());
+
+        let mut capped = SharedMemoryStorageLogger::new(2);
+        for index in 0..3u8 {
+            capped.log(Record::new(RecordKind::Read, format!("{index:02x}")));
+        }
+        let messages: Vec<String> = capped
+            .snapshot()
+            .into_iter()
+            .map(|record| record.message)
+            .collect();
+        assert_eq!(messages, ["01", "02"]);
+        capped.clear_log_records();
+        assert!(capped.is_empty());
+    }
+
+    #[test]
+    fn test_byte_budget_logger() {
+        let mut logger = ByteBudgetLogger::new(16);
+        assert_eq!(logger.max_bytes(), 16);
+
+        for (index, size) in [4usize, 1, 9, 3, 12, 2, 7, 16, 5].into_iter().enumerate() {
+            logger.log(Record::new(RecordKind::Write, "a".repeat(size)));
+            let records = logger.get_log_records();
+            let total: usize = records.iter().map(|record| record.message.len()).sum();
+            assert!(total <= 16);
+            assert_eq!(logger.total_bytes(), total);
+            // The most recent log record always fits, since none of messages exceeds the budget.
+            assert_eq!(
+                records.back().unwrap().message.len(),
+                size,
+                "record {index}"
+            );
+        }
+        let sizes: Vec<usize> = logger
+            .get_log_records()
+            .iter()
+            .map(|record| record.message.len())
+            .collect();
+        assert_eq!(sizes, [5]);
+
+        logger.log(Record::new(RecordKind::Read, String::from("abc")));
+        assert_eq!(logger.len(), 2);
+        assert_eq!(logger.total_bytes(), 8);
+
+        // Message longer than the whole budget evicts everything including itself.
+        logger.log(Record::new(RecordKind::Read, "b".repeat(17)));
+        assert!(logger.is_empty());
+        assert_eq!(logger.total_bytes(), 0);
+
+        logger.log(Record::new(RecordKind::Read, String::from("abc")));
+        assert_eq!(logger.drain_log_records().len(), 1);
+        assert_eq!(logger.total_bytes(), 0);
+        logger.log(Record::new(RecordKind::Read, String::from("abc")));
+        logger.clear_log_records();
+        assert!(logger.is_empty());
+        assert_eq!(logger.total_bytes(), 0);
+    }
+
+    #[test]
+    fn test_background_logger() {
+        use crate::buffer_formatter::HexadecimalFormatter;
+        use crate::filter::DefaultFilter;
+        use crate::stream::LoggedStream;
+        use std::io::Write;
+        use std::time::Duration;
+        use std::time::Instant;
+
+        let storage = SharedMemoryStorageLogger::new_unbounded();
+        let mut logged_stream = LoggedStream::new(
+            std::io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            BackgroundLogger::new(storage.clone()),
+        );
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+
+        // Log records are processed by the background thread, so they arrive with a delay.
+        let started = Instant::now();
+        while storage.len() < 2 && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let kinds = storage
+            .snapshot()
+            .iter()
+            .map(|record| record.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [RecordKind::Open, RecordKind::Write]);
+
+        for byte in 0..50u8 {
+            logged_stream.write_all(&[byte]).unwrap();
+        }
+        // Dropping joins the background thread after all remaining log records are processed.
+        drop(logged_stream);
+        let records = storage.snapshot();
+        assert_eq!(records.len(), 53);
+        assert_eq!(records[51].message, String::from("31"));
+        assert_eq!(records[52].kind, RecordKind::Drop);
+    }
+
+    #[test]
+    fn test_multi_logger() {
+        use std::sync::Arc;
+        use std::sync::Mutex;
+
+        struct SharedLogger(Arc<Mutex<MemoryStorageLogger>>);
+
+        impl Logger for SharedLogger {
+            fn log(&mut self, record: Record) {
+                self.0.lock().unwrap().log(record)
+            }
+        }
+
+        let memory = Arc::new(Mutex::new(MemoryStorageLogger::new(100)));
+        let mut channel = ChannelLogger::new();
+        let receiver = channel.take_receiver_unchecked();
+
+        let mut logger =
+            MultiLogger::new(vec![Box::new(SharedLogger(memory.clone()))]).push(channel);
+        let record = Record::new(RecordKind::Write, String::from("01:02:03"));
+        logger.log(record.clone());
+
+        let records = memory.lock().unwrap().get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0], record);
+        assert_eq!(receiver.try_recv().unwrap(), record);
+        assert!(receiver.try_recv().is_err());
+
+        // Empty logger just drops log records.
+        MultiLogger::default().log(record);
+    }
+
+    #[test]
+    fn test_sampling_logger() {
+        let mut logger = SamplingLogger::new(MemoryStorageLogger::new(100), 3);
+        for index in 0..9u8 {
+            logger.log(Record::new(RecordKind::Write, format!("{index:02x}")));
+        }
+        let messages = logger
+            .inner()
+            .get_log_records()
+            .into_iter()
+            .map(|record| record.message)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["00", "03", "06"]);
+
+        let mut logger = SamplingLogger::new(MemoryStorageLogger::new(100), 0);
+        for _ in 0..5 {
+            logger.log(Record::new(RecordKind::Read, String::from("01")));
+        }
+        assert_eq!(logger.inner().len(), 5);
+    }
+
+    #[test]
+    fn test_map_logger() {
+        let mut logger = MapLogger::new(MemoryStorageLogger::new(100), |record: Record| Record {
+            message: record.message.to_uppercase(),
+            ..record
+        });
+        logger.log(Record::with_byte_count(
+            RecordKind::Write,
+            String::from("0a:bc:de"),
+            3,
+        ));
+        logger.log(Record::new(RecordKind::Drop, String::from("Deallocated.")));
+
+        let records = logger.inner().get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[0].message, String::from("0A:BC:DE"));
+        assert_eq!(records[0].byte_count, Some(3));
+        assert_eq!(records[1].kind, RecordKind::Drop);
+        assert_eq!(records[1].message, String::from("DEALLOCATED."));
+
+        // Closure may keep state between log records, for example to number them.
+        let mut index = 0;
+        let mut logger = MapLogger::new(MemoryStorageLogger::new(100), move |record: Record| {
+            index += 1;
+            Record {
+                message: format!("#{index} {}", record.message),
+                ..record
+            }
+        });
+        logger.log(Record::new(RecordKind::Read, String::from("01")));
+        logger.log(Record::new(RecordKind::Read, String::from("02")));
+        let messages = logger
+            .inner()
+            .get_log_records()
+            .into_iter()
+            .map(|record| record.message)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["#1 01", "#2 02"]);
+    }
+
+    #[test]
+    fn test_fn_logger() {
+        use std::sync::Arc;
+        use std::sync::Mutex;
+
+        let storage = Arc::new(Mutex::new(Vec::new()));
+        let mut logger = FnLogger::new({
+            let storage = storage.clone();
+            move |record| storage.lock().unwrap().push(record)
+        });
+
+        let records = [
+            Record::new(RecordKind::Write, String::from("01:02:03")),
+            Record::new(RecordKind::Read, String::from("04:05:06")),
+        ];
+        for record in records.iter().cloned() {
+            logger.log(record);
+        }
+        assert_eq!(*storage.lock().unwrap(), records);
+
+        let mut boxed: Box<dyn Logger> = Box::new(logger);
+        boxed.log(Record::new(RecordKind::Drop, String::from("Deallocated.")));
+        assert_eq!(storage.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_write_logger() {
+        use chrono::TimeZone;
+        use chrono::Utc;
+
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut logger = WriteLogger::new(Vec::new());
+        logger.log(Record::with_time(
+            RecordKind::Write,
+            String::from("01:02:03"),
+            time,
+        ));
+        logger.log(Record::with_time(
+            RecordKind::Read,
+            String::from("04:05"),
+            time,
+        ));
+        // Log records are kept in the inner buffer until flushed.
+        assert!(logger.get_ref().is_empty());
+        logger.flush().unwrap();
+        assert_eq!(
+            logger.get_ref().as_slice(),
+            b"[2024-01-01T00:00:00+00:00] > 01:02:03\n[2024-01-01T00:00:00+00:00] < 04:05\n"
+        );
+
+        let mut logger = WriteLogger::new(std::io::Cursor::new(Vec::new()))
+            .with_flush_each_record(true)
+            .with_time_format(String::from("%H:%M"));
+        logger.log(Record::with_time(
+            RecordKind::Drop,
+            String::from("Deallocated."),
+            time,
+        ));
+        assert_eq!(
+            logger.get_ref().get_ref().as_slice(),
+            b"[00:00] x Deallocated.\n"
+        );
+    }
+
+    #[test]
+    fn test_file_logger_open() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("traffic.log");
+
+        let mut logger = FileLogger::open(&path, true).unwrap();
+        logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        drop(logger);
+
+        let mut logger = FileLogger::open(&path, true).unwrap();
+        logger.log(Record::new(RecordKind::Read, String::from("04:05:06")));
+        drop(logger);
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.contains("> 01:02:03"));
+        assert!(content.contains("< 04:05:06"));
+
+        let mut logger = FileLogger::open(&path, false).unwrap();
+        logger.log(Record::new(RecordKind::Error, String::from("failure")));
+        drop(logger);
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 1);
+        assert!(content.contains("! failure"));
+    }
+
+    #[test]
+    fn test_file_logger_flush() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("traffic.log");
+
+        let mut logger = FileLogger::open(&path, false).unwrap();
+        for _ in 0..100 {
+            logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        }
+        drop(logger);
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 100);
+
+        let mut logger = FileLogger::open(&path, false)
+            .unwrap()
+            .with_flush_each_record(true);
+        logger.log(Record::new(RecordKind::Read, String::from("04:05:06")));
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+        let mut logger = FileLogger::open(&path, true).unwrap();
+        logger.log(Record::new(RecordKind::Read, String::from("07:08:09")));
+        logger.flush().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 2);
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn test_gzip_file_logger() {
+        use crate::logger::GzipFileLogger;
+        use std::io::Read;
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("traffic.log.gz");
+
+        let mut logger = GzipFileLogger::open(&path, false).unwrap();
+        for _ in 0..100 {
+            logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        }
+        logger.log(Record::new(RecordKind::Read, String::from("04:05:06")));
+        drop(logger);
+
+        let mut logger = GzipFileLogger::open(&path, true).unwrap();
+        logger.log(Record::new(RecordKind::Error, String::from("failure")));
+        drop(logger);
+
+        let compressed = fs::read(&path).unwrap();
+        let mut content = String::new();
+        flate2::read::MultiGzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut content)
+            .unwrap();
+        assert!(compressed.len() < content.len());
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 102);
+        assert!(lines[0].ends_with("] > 01:02:03"));
+        assert!(lines[100].ends_with("] < 04:05:06"));
+        assert!(lines[101].ends_with("] ! failure"));
+    }
+
+    #[test]
+    fn test_file_logger_time_format() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("traffic.log");
+
+        let mut logger = FileLogger::open(&path, false)
+            .unwrap()
+            .with_time_format(String::from("%Y-%m-%d %H:%M:%S"));
+        logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        drop(logger);
+
+        let content = fs::read_to_string(&path).unwrap();
+        let line = content.lines().next().unwrap();
+        let (time, rest) = line.strip_prefix('[').unwrap().split_once(']').unwrap();
+        assert_eq!(rest, " > 01:02:03");
+        assert!(chrono::NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S").is_ok());
+
+        let mut logger = FileLogger::open(&path, false)
+            .unwrap()
+            .with_time_format(String::from("%Y-%Q"));
+        logger.log(Record::new(RecordKind::Read, String::from("04:05:06")));
+        drop(logger);
+
+        let content = fs::read_to_string(&path).unwrap();
+        let line = content.lines().next().unwrap();
+        let (time, rest) = line.strip_prefix('[').unwrap().split_once(']').unwrap();
+        assert_eq!(rest, " < 04:05:06");
+        assert!(chrono::DateTime::parse_from_rfc3339(time).is_ok());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_logger() {
+        use crate::logger::TracingLogger;
+        use std::sync::Arc;
+        use std::sync::Mutex;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        #[derive(Default)]
+        struct Visitor {
+            fields: Vec<(String, String)>,
+        }
+
+        impl tracing::field::Visit for Visitor {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                self.fields
+                    .push((field.name().to_string(), format!("{value:?}")));
+            }
+        }
+
+        type Events = Arc<Mutex<Vec<(tracing::Level, Vec<(String, String)>)>>>;
+
+        struct CaptureLayer {
+            events: Events,
+        }
+
+        impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CaptureLayer {
+            fn on_event(
+                &self,
+                event: &tracing::Event<'_>,
+                _ctx: tracing_subscriber::layer::Context<'_, S>,
+            ) {
+                let mut visitor = Visitor::default();
+                event.record(&mut visitor);
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), visitor.fields));
+            }
+        }
+
+        let events = Events::default();
+        let subscriber = tracing_subscriber::registry().with(CaptureLayer {
+            events: events.clone(),
+        });
+
+        tracing::subscriber::with_default(subscriber, || {
+            let mut logger = TracingLogger::new_unchecked("debug");
+            logger.log(Record::new(RecordKind::Read, String::from("01:02:03")));
+            logger.log(Record::new(RecordKind::Error, String::from("failure")));
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].0, tracing::Level::DEBUG);
+        assert_eq!(
+            events[0].1,
+            [
+                (String::from("kind"), String::from("Read")),
+                (String::from("message"), String::from("01:02:03")),
+            ]
+        );
+        assert_eq!(events[1].0, tracing::Level::ERROR);
+        assert_eq!(
+            events[1].1,
+            [
+                (String::from("kind"), String::from("Error")),
+                (String::from("message"), String::from("failure")),
+            ]
+        );
+
+        assert!(TracingLogger::new("invalid").is_err());
+    }
+
+    #[test]
+    fn test_null_logger() {
+        let mut logger: Box<dyn Logger> = Box::new(NullLogger);
+        for index in 0..10000 {
+            logger.log(Record::new(RecordKind::Read, format!("{index}")));
+        }
+        assert_eq!(std::mem::size_of::<NullLogger>(), 0);
+    }
+
+    #[test]
+    fn test_rolling_file_logger() {
+        let directory = tempfile::tempdir().unwrap();
+        let base_path = directory.path().join("traffic.log");
+        let message = String::from("01:02:03:04:05:06:07:08");
+
+        // Every log line is longer than a half of threshold, so each record triggers rotation.
+        let mut logger = RollingFileLogger::new(&base_path, 100, 2).unwrap();
+        logger.log(Record::new(RecordKind::Write, message.clone()));
+        logger.log(Record::new(RecordKind::Read, message.clone()));
+        logger.log(Record::new(RecordKind::Write, message.clone()));
+
+        let mut files = fs::read_dir(directory.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<String>>();
+        files.sort();
+        assert_eq!(files, ["traffic.log", "traffic.log.1", "traffic.log.2"]);
+
+        let read = |name: &str| fs::read_to_string(directory.path().join(name)).unwrap();
+        assert!(read("traffic.log").contains("> 01:02:03:04:05:06:07:08"));
+        assert!(read("traffic.log.1").contains("< 01:02:03:04:05:06:07:08"));
+        assert!(read("traffic.log.2").contains("> 01:02:03:04:05:06:07:08"));
+
+        // Oldest rotation is removed when max files number is reached.
+        logger.log(Record::new(RecordKind::Error, message));
+        let mut files = fs::read_dir(directory.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<String>>();
+        files.sort();
+        assert_eq!(files, ["traffic.log", "traffic.log.1", "traffic.log.2"]);
+        assert!(read("traffic.log").contains("! 01:02:03:04:05:06:07:08"));
+        assert!(read("traffic.log.1").contains("> 01:02:03:04:05:06:07:08"));
+        assert!(read("traffic.log.2").contains("< 01:02:03:04:05:06:07:08"));
+    }
+
+    #[cfg(feature = "async-logger")]
+    #[tokio::test]
+    async fn test_tokio_file_logger() {
+        use crate::buffer_formatter::HexadecimalFormatter;
+        use crate::filter::DefaultFilter;
+        use crate::logger::AsyncLogger;
+        use crate::logger::SpawnedLogger;
+        use crate::logger::TokioFileLogger;
+        use crate::stream::LoggedStream;
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("traffic.log");
+
+        let mut logger = TokioFileLogger::open(&path, false)
+            .await
+            .unwrap()
+            .with_time_format(String::from("%H:%M:%S"));
+        logger
+            .log(Record::new(RecordKind::Error, String::from("failure")))
+            .await;
+        drop(logger);
+        assert!(fs::read_to_string(&path).unwrap().contains("! failure"));
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        assert!(TokioFileLogger::open_with_options(&path, &options)
+            .await
+            .is_err());
+        let other_path = directory.path().join("other.log");
+        let mut logger = TokioFileLogger::open_with_options(&other_path, &options)
+            .await
+            .unwrap();
+        logger
+            .log(Record::new(RecordKind::Read, String::from("04:05")))
+            .await;
+        drop(logger);
+        assert!(fs::read_to_string(&other_path).unwrap().contains("< 04:05"));
+
+        let logger = SpawnedLogger::new(TokioFileLogger::open(&path, true).await.unwrap());
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut logged_stream = LoggedStream::new(
+            client,
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            logger,
+        );
+        logged_stream.write_all(&[0x01, 0x02, 0x03]).await.unwrap();
+        let mut buffer = [0u8; 3];
+        server.read_exact(&mut buffer).await.unwrap();
+        drop(logged_stream);
+
+        // Log records are written by a separate task, so wait until it catches up.
+        let mut content = String::new();
+        for _ in 0..100 {
+            content = fs::read_to_string(&path).unwrap();
+            if content.contains("x Deallocated.") {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains("! failure"));
+        assert!(lines[1].contains("+ Opened."));
+        assert!(lines[2].contains("> 01:02:03"));
+        assert!(lines[3].contains("x Deallocated."));
+    }
+
+    #[cfg(feature = "rusqlite")]
+    #[test]
+    fn test_sqlite_logger() {
+        use crate::logger::SqliteLogger;
+
+        let mut logger =
+            SqliteLogger::new(rusqlite::Connection::open_in_memory().unwrap()).unwrap();
+        logger.log(Record::new(RecordKind::Open, String::from("Opened.")));
+        logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        let mut record = Record::new(RecordKind::Read, String::from("04:05"));
+        record.time = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .into();
+        logger.log(record);
+
+        let connection = logger.connection();
+        let count: i64 = connection
+            .query_row("SELECT COUNT(*) FROM records", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 3);
+        let row: (String, String, String) = connection
+            .query_row(
+                "SELECT time, kind, message FROM records WHERE kind = 'read'",
+                (),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(
+            row,
+            (
+                String::from("2024-01-01T00:00:00Z"),
+                String::from("read"),
+                String::from("04:05")
+            )
+        );
+        assert_eq!(logger.failed_count(), 0);
+
+        logger
+            .connection()
+            .execute("DROP TABLE records", ())
+            .unwrap();
+        logger.log(Record::new(RecordKind::Drop, String::from("Deallocated.")));
+        logger.log(Record::new(RecordKind::Drop, String::from("Deallocated.")));
+        assert_eq!(logger.failed_count(), 2);
+    }
+
+    #[cfg(feature = "syslog")]
+    #[test]
+    fn test_syslog_logger() {
+        use crate::logger::SyslogLogger;
+        use std::io;
+        use std::sync::Arc;
+        use std::sync::Mutex;
+
+        #[derive(Clone, Default)]
+        struct SharedBuffer(Arc<Mutex<Vec<String>>>);
+
+        impl io::Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let message = String::from_utf8_lossy(buf).into_owned();
+                self.0.lock().unwrap().push(message);
+                Ok(buf.len())
+            }
+
+            // Mirror syslog backends which send each formatted message as a single datagram.
+            fn write_fmt(&mut self, args: std::fmt::Arguments<'_>) -> io::Result<()> {
+                self.0.lock().unwrap().push(args.to_string());
+                Ok(())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer::default();
+        let formatter = syslog::Formatter3164 {
+            facility: syslog::Facility::LOG_USER,
+            hostname: None,
+            process: String::from("logged-stream"),
+            pid: 42,
+        };
+        let mut logger = SyslogLogger::from_logger(
+            syslog::Logger::new(buffer.clone(), formatter),
+            syslog::Severity::LOG_INFO,
+        );
+        logger.log(Record::new(RecordKind::Write, String::from("01:02:03")));
+        logger.log(Record::new(RecordKind::Error, String::from("failure")));
+
+        let messages = buffer.0.lock().unwrap().clone();
+        assert_eq!(messages.len(), 2);
+        // Priority is a sum of facility (user, 8) and severity (info, 6 or error, 3).
+        assert!(messages[0].starts_with("<14>"));
+        assert!(messages[0].ends_with("logged-stream[42]: > 01:02:03"));
+        assert!(messages[1].starts_with("<11>"));
+        assert!(messages[1].ends_with("logged-stream[42]: ! failure"));
     }
 
     fn assert_logger<T: Logger>() {}
@@ -271,10 +3008,24 @@ mod tests {
     #[test]
     fn test_box() {
         assert_logger::<Box<dyn Logger>>();
+        assert_logger::<Box<dyn Logger + Send>>();
+        assert_logger::<Box<dyn Logger + Sync>>();
+        assert_logger::<Box<dyn Logger + Send + Sync>>();
         assert_logger::<Box<ConsoleLogger>>();
         assert_logger::<Box<MemoryStorageLogger>>();
+        assert_logger::<Box<SharedMemoryStorageLogger>>();
+        assert_logger::<Box<ByteBudgetLogger>>();
+        assert_logger::<Box<BackgroundLogger>>();
         assert_logger::<Box<ChannelLogger>>();
         assert_logger::<Box<FileLogger>>();
+        assert_logger::<Box<WriteLogger<Vec<u8>>>>();
+        assert_logger::<Box<RollingFileLogger>>();
+        assert_logger::<Box<MultiLogger>>();
+        assert_logger::<Box<NullLogger>>();
+        assert_logger::<Box<FnLogger>>();
+        assert_logger::<Box<SamplingLogger<NullLogger>>>();
+        assert_logger::<Box<MapLogger<NullLogger>>>();
+        assert_logger::<SamplingLogger<Box<dyn Logger>>>();
     }
 
     fn assert_send<T: Send>() {}
@@ -283,13 +3034,32 @@ mod tests {
     fn test_send() {
         assert_send::<ConsoleLogger>();
         assert_send::<MemoryStorageLogger>();
+        assert_send::<SharedMemoryStorageLogger>();
+        assert_send::<ByteBudgetLogger>();
+        assert_send::<BackgroundLogger>();
         assert_send::<ChannelLogger>();
         assert_send::<FileLogger>();
+        assert_send::<WriteLogger<Vec<u8>>>();
+        assert_send::<RollingFileLogger>();
+        assert_send::<MultiLogger>();
+        assert_send::<NullLogger>();
+        assert_send::<FnLogger>();
+        assert_send::<SamplingLogger<NullLogger>>();
+        assert_send::<MapLogger<NullLogger>>();
 
         assert_send::<Box<dyn Logger>>();
         assert_send::<Box<ConsoleLogger>>();
         assert_send::<Box<MemoryStorageLogger>>();
+        assert_send::<Box<SharedMemoryStorageLogger>>();
+        assert_send::<Box<ByteBudgetLogger>>();
+        assert_send::<Box<BackgroundLogger>>();
         assert_send::<Box<ChannelLogger>>();
         assert_send::<Box<FileLogger>>();
+        assert_send::<Box<WriteLogger<Vec<u8>>>>();
+        assert_send::<Box<RollingFileLogger>>();
+        assert_send::<Box<MultiLogger>>();
+        assert_send::<Box<NullLogger>>();
+        assert_send::<Box<FnLogger>>();
+        assert_send::<Box<SamplingLogger<NullLogger>>>();
     }
 }
@@ -1,29 +1,230 @@
+use crate::clock::Clock;
 use chrono::DateTime;
+use chrono::SecondsFormat;
 use chrono::Utc;
+use std::error;
 use std::fmt;
+use std::io;
+use std::str;
+use std::sync::atomic;
+use std::time;
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Record
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// This structure represents a log record and contains message string, creation timestamp ([`DateTime`]<[`Utc`]>)
-/// and record kind ([`RecordKind`]).
+/// This structure represents a log record and contains message string, creation timestamp ([`DateTime`]<[`Utc`]>),
+/// record kind ([`RecordKind`]), optional number of transferred bytes, optional transferred bytes themselves,
+/// optional duration of the underlying IO call, optional sequence number, optional label and optional details of
+/// IO error.
+///
+/// With `serde` feature enabled it implements `Serialize` and `Deserialize` traits, timestamp is serialized in
+/// RFC 3339 format.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Record {
     pub kind: RecordKind,
     pub message: String,
     pub time: DateTime<Utc>,
+    /// Real number of bytes transferred by read or write operation. It is [`None`] for other log record kinds.
+    pub byte_count: Option<usize>,
+    /// Label of [`LoggedStream`] which emitted log record, for example peer address of TCP connection.
+    ///
+    /// [`LoggedStream`]: crate::LoggedStream
+    pub label: Option<String>,
+    /// Bytes transferred by read or write operation. It is [`None`] unless keeping of transferred bytes was
+    /// enabled using [`LoggedStream::with_keep_raw`] method.
+    ///
+    /// [`LoggedStream::with_keep_raw`]: crate::LoggedStream::with_keep_raw
+    pub raw: Option<Vec<u8>>,
+    /// Time spent in the underlying read or write call which transferred bytes, useful for latency analysis. In
+    /// case of asynchronous IO it is time spent in the single successful poll call. It is [`None`] for other log
+    /// record kinds.
+    pub duration: Option<time::Duration>,
+    /// Sequence number assigned to log record right before it is filtered, using counter shared by all
+    /// [`LoggedStream`] instances. It strictly increases, so log records created within the same timestamp tick
+    /// remain orderable. It is [`None`] for log records constructed manually.
+    ///
+    /// [`LoggedStream`]: crate::LoggedStream
+    pub seq: Option<u64>,
+    /// Kind of IO error described by log record with [`Error`] kind, which allows to categorize failures without
+    /// parsing message. It is [`None`] for other log record kinds. It is serialized as name of the kind, for
+    /// example `ConnectionReset`, since [`io::ErrorKind`] doesn't implement serde traits.
+    ///
+    /// [`Error`]: RecordKind::Error
+    #[cfg_attr(feature = "serde", serde(with = "error_kind_serde"))]
+    pub error_kind: Option<io::ErrorKind>,
+    /// Raw OS error code of IO error described by log record with [`Error`] kind, see
+    /// [`io::Error::raw_os_error`]. It is [`None`] for other log record kinds and for errors which didn't come
+    /// from the operating system.
+    ///
+    /// [`Error`]: RecordKind::Error
+    pub os_error: Option<i32>,
 }
 
 impl Record {
-    /// Construct a new instance of [`Record`] using provided message and kind.
+    /// Construct a new instance of [`Record`] using provided message and kind. Creation timestamp is set to
+    /// current time.
     pub fn new(kind: RecordKind, message: String) -> Self {
+        Self::with_time(kind, message, Utc::now())
+    }
+
+    /// Construct a new instance of [`Record`] using provided message, kind and creation timestamp. Useful for
+    /// deterministic testing and replaying of previously recorded log records.
+    pub fn with_time(kind: RecordKind, message: String, time: DateTime<Utc>) -> Self {
         Self {
             kind,
             message,
-            time: Utc::now(),
+            time,
+            byte_count: None,
+            label: None,
+            raw: None,
+            duration: None,
+            seq: None,
+            error_kind: None,
+            os_error: None,
+        }
+    }
+
+    /// Construct a new instance of [`Record`] using provided message, kind and number of transferred bytes.
+    pub fn with_byte_count(kind: RecordKind, message: String, byte_count: usize) -> Self {
+        Self {
+            byte_count: Some(byte_count),
+            ..Self::new(kind, message)
         }
     }
+
+    /// Construct a new instance of [`Record`] using provided message, kind and transferred bytes. Number of
+    /// transferred bytes is set to the length of provided bytes.
+    pub fn with_raw(kind: RecordKind, message: String, raw: Vec<u8>) -> Self {
+        Self {
+            byte_count: Some(raw.len()),
+            raw: Some(raw),
+            ..Self::new(kind, message)
+        }
+    }
+
+    /// Construct a new instance of [`Record`] using provided message, kind and sequence number. Useful for
+    /// replaying of previously recorded log records.
+    pub fn with_seq(kind: RecordKind, message: String, seq: u64) -> Self {
+        Self {
+            seq: Some(seq),
+            ..Self::new(kind, message)
+        }
+    }
+
+    /// Construct a new instance of [`Record`] using provided message, kind and IO error, which kind and raw OS
+    /// error code are stored in [`error_kind`] and [`os_error`] fields respectively.
+    ///
+    /// [`error_kind`]: Record::error_kind
+    /// [`os_error`]: Record::os_error
+    pub fn with_io_error(kind: RecordKind, message: String, error: &io::Error) -> Self {
+        Self {
+            error_kind: Some(error.kind()),
+            os_error: error.raw_os_error(),
+            ..Self::new(kind, message)
+        }
+    }
+
+    /// Appends `(partial N/M)` annotation to message of log record describing write operation which transferred
+    /// fewer bytes than requested, so short writes caused by backpressure are visible in logs.
+    pub(crate) fn mark_partial_write(&mut self, written: usize, requested: usize) {
+        if written < requested {
+            self.message = format!("{} (partial {written}/{requested})", self.message);
+        }
+    }
+
+    /// Attaches provided label and the next sequence number to log record right before it is filtered. Creation
+    /// timestamp is replaced with the current time of provided clock, if any.
+    pub(crate) fn stamp(&mut self, label: Option<&str>, clock: Option<&dyn Clock>) {
+        self.label = label.map(String::from);
+        self.seq = Some(next_seq());
+        if let Some(clock) = clock {
+            self.time = clock.now();
+        }
+    }
+
+    /// Returns bytes transferred by read or write operation, if they were kept.
+    #[inline]
+    pub fn message_bytes(&self) -> Option<&[u8]> {
+        self.raw.as_deref()
+    }
+}
+
+impl fmt::Display for Record {
+    /// Formats log record as its RFC 3339 timestamp in square brackets followed by single-char kind
+    /// representation and message, for example `[2024-01-01T00:00:00Z] < 01:02:03`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {} {}",
+            self.time.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            self.kind,
+            self.message
+        )
+    }
+}
+
+/// Returns next value of the global log record sequence counter, see [`Record::seq`].
+pub(crate) fn next_seq() -> u64 {
+    static SEQ: atomic::AtomicU64 = atomic::AtomicU64::new(0);
+    SEQ.fetch_add(1, atomic::Ordering::Relaxed)
+}
+
+/// Serialization of [`Record::error_kind`] field using name of [`io::ErrorKind`], which is the same as its
+/// [`Debug`] output. Names of kinds introduced after the minimum supported Rust version are deserialized as
+/// [`io::ErrorKind::Other`].
+///
+/// [`Debug`]: fmt::Debug
+#[cfg(feature = "serde")]
+mod error_kind_serde {
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+    use std::io;
+
+    const KINDS: [io::ErrorKind; 20] = [
+        io::ErrorKind::NotFound,
+        io::ErrorKind::PermissionDenied,
+        io::ErrorKind::ConnectionRefused,
+        io::ErrorKind::ConnectionReset,
+        io::ErrorKind::ConnectionAborted,
+        io::ErrorKind::NotConnected,
+        io::ErrorKind::AddrInUse,
+        io::ErrorKind::AddrNotAvailable,
+        io::ErrorKind::BrokenPipe,
+        io::ErrorKind::AlreadyExists,
+        io::ErrorKind::WouldBlock,
+        io::ErrorKind::InvalidInput,
+        io::ErrorKind::InvalidData,
+        io::ErrorKind::TimedOut,
+        io::ErrorKind::WriteZero,
+        io::ErrorKind::Interrupted,
+        io::ErrorKind::Unsupported,
+        io::ErrorKind::UnexpectedEof,
+        io::ErrorKind::OutOfMemory,
+        io::ErrorKind::Other,
+    ];
+
+    pub(super) fn serialize<S: Serializer>(
+        kind: &Option<io::ErrorKind>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        kind.map(|kind| format!("{kind:?}")).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<io::ErrorKind>, D::Error> {
+        let name = Option::<String>::deserialize(deserializer)?;
+        Ok(name.map(|name| {
+            KINDS
+                .into_iter()
+                .find(|kind| format!("{kind:?}") == name)
+                .unwrap_or(io::ErrorKind::Other)
+        }))
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -32,13 +233,25 @@ impl Record {
 
 /// This enumeration represents log record kind. It is contained inside [`Record`] and helps to determine
 /// how to work with log record message content which is different for each log record kind.
+///
+/// With `serde` feature enabled it implements `Serialize` and `Deserialize` traits using lowercase names returned
+/// by [`name`] method.
+///
+/// [`name`]: RecordKind::name
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum RecordKind {
     Open,
+    Connect,
     Read,
+    Eof,
     Write,
     Error,
     Shutdown,
+    Flush,
+    Seek,
+    Pending,
     Drop,
 }
 
@@ -48,15 +261,255 @@ impl fmt::Display for RecordKind {
     }
 }
 
+impl RecordKind {
+    /// All log record kinds in declaration order, for example to iterate over per-kind statistics.
+    pub const ALL: [RecordKind; 11] = [
+        RecordKind::Open,
+        RecordKind::Connect,
+        RecordKind::Read,
+        RecordKind::Eof,
+        RecordKind::Write,
+        RecordKind::Error,
+        RecordKind::Shutdown,
+        RecordKind::Flush,
+        RecordKind::Seek,
+        RecordKind::Pending,
+        RecordKind::Drop,
+    ];
+
+    /// Returns position of log record kind in [`ALL`] array, which is used to index per-kind tables sized by it.
+    ///
+    /// [`ALL`]: RecordKind::ALL
+    pub(crate) const fn index(self) -> usize {
+        match self {
+            RecordKind::Open => 0,
+            RecordKind::Connect => 1,
+            RecordKind::Read => 2,
+            RecordKind::Eof => 3,
+            RecordKind::Write => 4,
+            RecordKind::Error => 5,
+            RecordKind::Shutdown => 6,
+            RecordKind::Flush => 7,
+            RecordKind::Seek => 8,
+            RecordKind::Pending => 9,
+            RecordKind::Drop => 10,
+        }
+    }
+
+    /// Returns human-readable lowercase name of log record kind, for example `read` or `shutdown`. It is distinct
+    /// from [`Display`] implementation which outputs single-char representation, and can be parsed back using
+    /// [`FromStr`] implementation.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [`FromStr`]: str::FromStr
+    pub fn name(&self) -> &'static str {
+        match self {
+            RecordKind::Open => "open",
+            RecordKind::Connect => "connect",
+            RecordKind::Read => "read",
+            RecordKind::Eof => "eof",
+            RecordKind::Write => "write",
+            RecordKind::Error => "error",
+            RecordKind::Shutdown => "shutdown",
+            RecordKind::Flush => "flush",
+            RecordKind::Seek => "seek",
+            RecordKind::Pending => "pending",
+            RecordKind::Drop => "drop",
+        }
+    }
+}
+
+// Every kind is placed in `RecordKind::ALL` at its own index, so per-kind tables never mix kinds up.
+const _: () = {
+    let mut i = 0;
+    while i < RecordKind::ALL.len() {
+        assert!(RecordKind::ALL[i].index() == i);
+        i += 1;
+    }
+};
+
+impl str::FromStr for RecordKind {
+    type Err = ParseRecordKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "open" => Ok(RecordKind::Open),
+            "connect" => Ok(RecordKind::Connect),
+            "read" => Ok(RecordKind::Read),
+            "eof" => Ok(RecordKind::Eof),
+            "write" => Ok(RecordKind::Write),
+            "error" => Ok(RecordKind::Error),
+            "shutdown" => Ok(RecordKind::Shutdown),
+            "flush" => Ok(RecordKind::Flush),
+            "seek" => Ok(RecordKind::Seek),
+            "pending" => Ok(RecordKind::Pending),
+            "drop" => Ok(RecordKind::Drop),
+            _ => Err(ParseRecordKindError),
+        }
+    }
+}
+
 impl From<RecordKind> for char {
     fn from(kind: RecordKind) -> Self {
         match kind {
             RecordKind::Open => '+',
+            RecordKind::Connect => '=',
             RecordKind::Read => '<',
+            RecordKind::Eof => '$',
             RecordKind::Write => '>',
             RecordKind::Error => '!',
             RecordKind::Shutdown => '-',
+            RecordKind::Flush => '~',
+            RecordKind::Seek => '@',
+            RecordKind::Pending => '?',
             RecordKind::Drop => 'x',
         }
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ParseRecordKindError
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned by [`RecordKind`] [`FromStr`] implementation when provided string is not a known log record
+/// kind name.
+///
+/// [`FromStr`]: str::FromStr
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseRecordKindError;
+
+impl fmt::Display for ParseRecordKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("attempted to convert a string that doesn't match an existing record kind")
+    }
+}
+
+impl error::Error for ParseRecordKindError {}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::record::ParseRecordKindError;
+    use crate::record::Record;
+    use crate::record::RecordKind;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_record_with_time() {
+        let time = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap();
+        let record = Record::with_time(RecordKind::Write, String::from("01:02:03"), time);
+        assert_eq!(record.time, time);
+        assert_eq!(record.kind, RecordKind::Write);
+        assert_eq!(record.message, String::from("01:02:03"));
+        assert_eq!(record.byte_count, None);
+        assert_eq!(record.label, None);
+        assert_eq!(record.duration, None);
+        assert_eq!(record.seq, None);
+        assert_eq!(record.clone(), record);
+    }
+
+    #[test]
+    fn test_record_display() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let record = Record::with_time(RecordKind::Read, String::from("01:02:03"), time);
+        assert_eq!(record.to_string(), "[2024-01-01T00:00:00Z] < 01:02:03");
+
+        let time = time + chrono::Duration::milliseconds(250);
+        let record = Record::with_time(RecordKind::Drop, String::from("Deallocated."), time);
+        assert_eq!(
+            record.to_string(),
+            "[2024-01-01T00:00:00.250Z] x Deallocated."
+        );
+    }
+
+    #[test]
+    fn test_record_with_seq() {
+        let record = Record::with_seq(RecordKind::Read, String::from("01:02"), 7);
+        assert_eq!(record.seq, Some(7));
+        assert_eq!(record.byte_count, None);
+    }
+
+    #[test]
+    fn test_record_kind_name_round_trip() {
+        for kind in RecordKind::ALL {
+            assert_eq!(RecordKind::from_str(kind.name()), Ok(kind));
+            assert_eq!(RecordKind::from_str(&kind.name().to_uppercase()), Ok(kind));
+            assert_ne!(kind.name(), kind.to_string());
+        }
+    }
+
+    #[test]
+    fn test_record_kind_from_str() {
+        assert_eq!(RecordKind::from_str("Read"), Ok(RecordKind::Read));
+        assert_eq!(RecordKind::from_str(" write "), Ok(RecordKind::Write));
+        assert_eq!(RecordKind::from_str(""), Err(ParseRecordKindError));
+        assert_eq!(RecordKind::from_str("<"), Err(ParseRecordKindError));
+        assert_eq!(RecordKind::from_str("reader"), Err(ParseRecordKindError));
+
+        let kinds: Result<Vec<RecordKind>, _> = "read,write,error"
+            .split(',')
+            .map(RecordKind::from_str)
+            .collect();
+        assert_eq!(
+            kinds,
+            Ok(vec![RecordKind::Read, RecordKind::Write, RecordKind::Error])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_record_serde_round_trip() {
+        use std::io;
+
+        let records = vec![
+            Record::new(RecordKind::Open, String::from("Opened.")),
+            Record::with_byte_count(RecordKind::Write, String::from("01:02:03"), 3),
+            Record::with_raw(RecordKind::Read, String::from("04:05"), vec![0x04, 0x05]),
+            Record {
+                label: Some(String::from("127.0.0.1:8080")),
+                ..Record::new(RecordKind::Drop, String::from("Deallocated."))
+            },
+            Record::with_io_error(
+                RecordKind::Error,
+                String::from("Error during read: not found"),
+                &io::Error::from_raw_os_error(2),
+            ),
+            Record::with_io_error(
+                RecordKind::Error,
+                String::from("Error during write: timed out"),
+                &io::Error::new(io::ErrorKind::TimedOut, "timed out"),
+            ),
+        ];
+
+        let json = serde_json::to_string(&records).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Record>>(&json).unwrap(), records);
+
+        let value = serde_json::to_value(&records[1]).unwrap();
+        assert_eq!(value["kind"], "write");
+        assert_eq!(value["byte_count"], 3);
+        let time = value["time"].as_str().unwrap();
+        assert_eq!(
+            chrono::DateTime::parse_from_rfc3339(time).unwrap(),
+            records[1].time
+        );
+
+        let decoded = serde_json::from_str::<Vec<Record>>(&json).unwrap();
+        assert_eq!(decoded[4].error_kind, Some(io::ErrorKind::NotFound));
+        assert_eq!(decoded[4].os_error, Some(2));
+        assert_eq!(decoded[5].error_kind, Some(io::ErrorKind::TimedOut));
+        assert_eq!(decoded[5].os_error, None);
+        let value = serde_json::to_value(&records[5]).unwrap();
+        assert_eq!(value["error_kind"], "TimedOut");
+
+        for kind in RecordKind::ALL {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, format!("\"{}\"", kind.name()));
+            assert_eq!(serde_json::from_str::<RecordKind>(&json).unwrap(), kind);
+        }
+    }
+}
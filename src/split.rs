@@ -0,0 +1,513 @@
+use crate::buffer_formatter::BufferFormatter;
+use crate::clock::Clock;
+use crate::filter::RecordFilter;
+use crate::logger::Logger;
+use crate::record::Record;
+use crate::record::RecordKind;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::sync;
+use std::task::Context;
+use std::task::Poll;
+use std::time;
+use tokio::io as tokio_io;
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Shared
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Parts of [`LoggedStream`] shared between its read and write halves. Log record with [`Drop`] kind is emitted
+/// when both halves are dropped.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`Drop`]: RecordKind::Drop
+struct Shared<Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> {
+    formatter: Formatter,
+    filter: Filter,
+    logger: L,
+    settings: Settings,
+}
+
+/// Settings of [`LoggedStream`] which are carried over to its read and write halves, so split halves behave
+/// exactly like the stream they were split from.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+pub(crate) struct Settings {
+    pub(crate) label: Option<String>,
+    pub(crate) clock: Option<sync::Arc<dyn Clock>>,
+    pub(crate) keep_raw: bool,
+    pub(crate) log_eof: bool,
+    pub(crate) log_drop: bool,
+    pub(crate) trace_pending: bool,
+    pub(crate) enabled: bool,
+}
+
+impl<Formatter: BufferFormatter + 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    Shared<Formatter, Filter, L>
+{
+    /// Logs transferred bytes as log record with provided kind unless logging is paused, in which case bytes are
+    /// not even formatted. In case if fewer bytes than requested were written, log record is marked as partial
+    /// write.
+    fn log_transfer(
+        &mut self,
+        kind: RecordKind,
+        buffer: &[u8],
+        requested: usize,
+        duration: time::Duration,
+    ) {
+        if !self.settings.enabled {
+            return;
+        }
+        let message = self.formatter.format_buffer_with_kind(kind, buffer);
+        let mut record = if self.settings.keep_raw {
+            Record::with_raw(kind, message, buffer.to_vec())
+        } else {
+            Record::with_byte_count(kind, message, buffer.len())
+        };
+        record.duration = Some(duration);
+        if kind == RecordKind::Write {
+            record.mark_partial_write(buffer.len(), requested);
+        }
+        self.log_filtered(record);
+    }
+}
+
+impl<Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    Shared<Formatter, Filter, L>
+{
+    fn log_filtered(&mut self, mut record: Record) {
+        if !self.settings.enabled {
+            return;
+        }
+        record.stamp(
+            self.settings.label.as_deref(),
+            self.settings.clock.as_deref(),
+        );
+        if self.filter.check(&record) {
+            self.logger.log(record);
+        }
+    }
+
+    fn log_unfiltered(&mut self, mut record: Record) {
+        if !self.settings.enabled {
+            return;
+        }
+        record.stamp(
+            self.settings.label.as_deref(),
+            self.settings.clock.as_deref(),
+        );
+        self.logger.log(record);
+    }
+
+    fn log_pending(&mut self, operation: &str) {
+        if self.settings.trace_pending {
+            self.log_filtered(Record::new(
+                RecordKind::Pending,
+                format!("Pending {operation}."),
+            ));
+        }
+    }
+}
+
+impl<Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> Drop
+    for Shared<Formatter, Filter, L>
+{
+    fn drop(&mut self) {
+        if self.settings.log_drop {
+            self.log_filtered(Record::new(RecordKind::Drop, String::from("Deallocated.")));
+        }
+    }
+}
+
+type SharedRef<Formatter, Filter, L> = sync::Arc<sync::Mutex<Shared<Formatter, Filter, L>>>;
+
+fn lock<Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>(
+    shared: &SharedRef<Formatter, Filter, L>,
+) -> sync::MutexGuard<'_, Shared<Formatter, Filter, L>> {
+    shared
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn split<
+    S: tokio_io::AsyncRead + tokio_io::AsyncWrite + 'static,
+    Formatter: BufferFormatter + 'static,
+    Filter: RecordFilter + 'static,
+    L: Logger + 'static,
+>(
+    stream: S,
+    formatter: Formatter,
+    filter: Filter,
+    logger: L,
+    settings: Settings,
+) -> (
+    LoggedReadHalf<S, Formatter, Filter, L>,
+    LoggedWriteHalf<S, Formatter, Filter, L>,
+) {
+    let (reader, writer) = tokio_io::split(stream);
+    let shared = sync::Arc::new(sync::Mutex::new(Shared {
+        formatter,
+        filter,
+        logger,
+        settings,
+    }));
+    (
+        LoggedReadHalf {
+            inner: reader,
+            shared: shared.clone(),
+        },
+        LoggedWriteHalf {
+            inner: writer,
+            shared,
+        },
+    )
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LoggedReadHalf
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read half of [`LoggedStream`] returned by [`LoggedStream::split`] method.
+///
+/// Formatting, filtering and logging parts are shared between both halves through [`sync::Arc`]<[`sync::Mutex`]>.
+/// The mutex is locked only while log record is constructed and passed to the logging part, and never while
+/// waiting for the underlying IO object, so halves don't block each other during IO operations. Log record with
+/// [`Drop`] kind is emitted once, when both halves are dropped.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`LoggedStream::split`]: crate::LoggedStream::split
+/// [`Drop`]: RecordKind::Drop
+pub struct LoggedReadHalf<
+    S: 'static,
+    Formatter: 'static,
+    Filter: RecordFilter + 'static,
+    L: Logger + 'static,
+> {
+    inner: tokio_io::ReadHalf<S>,
+    shared: SharedRef<Formatter, Filter, L>,
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedReadHalf<S, Formatter, Filter, L>
+{
+    /// Pauses or resumes logging of both halves, see [`LoggedStream::set_enabled`] for details. Halves inherit
+    /// this setting from [`LoggedStream`] they were split from.
+    ///
+    /// [`LoggedStream`]: crate::LoggedStream
+    /// [`LoggedStream::set_enabled`]: crate::LoggedStream::set_enabled
+    pub fn set_enabled(&self, enabled: bool) {
+        lock(&self.shared).settings.enabled = enabled;
+    }
+
+    /// Returns `true` if logging of both halves is not paused.
+    pub fn is_enabled(&self) -> bool {
+        lock(&self.shared).settings.enabled
+    }
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> fmt::Debug
+    for LoggedReadHalf<S, Formatter, Filter, L>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggedReadHalf").finish_non_exhaustive()
+    }
+}
+
+impl<
+        S: tokio_io::AsyncRead + 'static,
+        Formatter: BufferFormatter + 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > tokio_io::AsyncRead for LoggedReadHalf<S, Formatter, Filter, L>
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio_io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let mut_self = self.get_mut();
+        let requested = buf.remaining();
+        let length_before_read = buf.filled().len();
+        let started = time::Instant::now();
+        let result = Pin::new(&mut mut_self.inner).poll_read(cx, buf);
+        let elapsed = started.elapsed();
+        let length_after_read = buf.filled().len();
+        let diff = length_after_read - length_before_read;
+
+        match &result {
+            Poll::Ready(Ok(())) if diff == 0 && requested != 0 => {
+                let mut shared = lock(&mut_self.shared);
+                if shared.settings.log_eof {
+                    shared.log_filtered(Record::new(
+                        RecordKind::Eof,
+                        String::from("Reached end of stream."),
+                    ));
+                }
+            }
+            Poll::Ready(Ok(())) if diff == 0 => {}
+            Poll::Ready(Ok(())) => lock(&mut_self.shared).log_transfer(
+                RecordKind::Read,
+                &(buf.filled())[length_before_read..length_after_read],
+                requested,
+                elapsed,
+            ),
+            Poll::Ready(Err(e)) => lock(&mut_self.shared).log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during async read: {e}"),
+                e,
+            )),
+            Poll::Pending => lock(&mut_self.shared).log_pending("read"),
+        }
+
+        result
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LoggedWriteHalf
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Write half of [`LoggedStream`] returned by [`LoggedStream::split`] method.
+///
+/// See [`LoggedReadHalf`] for details about synchronization between halves.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`LoggedStream::split`]: crate::LoggedStream::split
+pub struct LoggedWriteHalf<
+    S: 'static,
+    Formatter: 'static,
+    Filter: RecordFilter + 'static,
+    L: Logger + 'static,
+> {
+    inner: tokio_io::WriteHalf<S>,
+    shared: SharedRef<Formatter, Filter, L>,
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedWriteHalf<S, Formatter, Filter, L>
+{
+    /// Pauses or resumes logging of both halves, see [`LoggedStream::set_enabled`] for details. Halves inherit
+    /// this setting from [`LoggedStream`] they were split from.
+    ///
+    /// [`LoggedStream`]: crate::LoggedStream
+    /// [`LoggedStream::set_enabled`]: crate::LoggedStream::set_enabled
+    pub fn set_enabled(&self, enabled: bool) {
+        lock(&self.shared).settings.enabled = enabled;
+    }
+
+    /// Returns `true` if logging of both halves is not paused.
+    pub fn is_enabled(&self) -> bool {
+        lock(&self.shared).settings.enabled
+    }
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> fmt::Debug
+    for LoggedWriteHalf<S, Formatter, Filter, L>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggedWriteHalf").finish_non_exhaustive()
+    }
+}
+
+impl<
+        S: tokio_io::AsyncWrite + 'static,
+        Formatter: BufferFormatter + 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > tokio_io::AsyncWrite for LoggedWriteHalf<S, Formatter, Filter, L>
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        let mut_self = self.get_mut();
+        let started = time::Instant::now();
+        let result = Pin::new(&mut mut_self.inner).poll_write(cx, buf);
+        let elapsed = started.elapsed();
+        match &result {
+            Poll::Ready(Ok(length)) => lock(&mut_self.shared).log_transfer(
+                RecordKind::Write,
+                &buf[0..*length],
+                buf.len(),
+                elapsed,
+            ),
+            Poll::Ready(Err(e)) => lock(&mut_self.shared).log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during async write: {e}"),
+                e,
+            )),
+            Poll::Pending => lock(&mut_self.shared).log_pending("write"),
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let mut_self = self.get_mut();
+        let result = Pin::new(&mut mut_self.inner).poll_flush(cx);
+        match &result {
+            Poll::Ready(Ok(())) => lock(&mut_self.shared).log_filtered(Record::new(
+                RecordKind::Flush,
+                String::from("Flush request."),
+            )),
+            Poll::Ready(Err(e)) => lock(&mut_self.shared).log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during async flush: {e}"),
+                e,
+            )),
+            Poll::Pending => {}
+        }
+        result
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let mut_self = self.get_mut();
+        let result = Pin::new(&mut mut_self.inner).poll_shutdown(cx);
+        lock(&mut_self.shared).log_filtered(Record::new(
+            RecordKind::Shutdown,
+            String::from("Writer shutdown request."),
+        ));
+        result
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::filter::DefaultFilter;
+    use crate::logger::ChannelLogger;
+    use crate::record::RecordKind;
+    use crate::split::LoggedReadHalf;
+    use crate::split::LoggedWriteHalf;
+    use crate::stream::LoggedStream;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+
+    type ReadHalf =
+        LoggedReadHalf<tokio::io::DuplexStream, HexadecimalFormatter, DefaultFilter, ChannelLogger>;
+    type WriteHalf = LoggedWriteHalf<
+        tokio::io::DuplexStream,
+        HexadecimalFormatter,
+        DefaultFilter,
+        ChannelLogger,
+    >;
+
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn test_unpin() {
+        assert_unpin::<ReadHalf>();
+        assert_unpin::<WriteHalf>();
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_send() {
+        assert_send::<ReadHalf>();
+        assert_send::<WriteHalf>();
+    }
+
+    #[tokio::test]
+    async fn test_split() {
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut logged_stream = LoggedStream::new(
+            client,
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            ChannelLogger::new(),
+        )
+        .with_label(String::from("client"));
+        let receiver = logged_stream.take_receiver_unchecked();
+        let (mut read_half, mut write_half) = logged_stream.split();
+
+        let writer = tokio::spawn(async move {
+            write_half.write_all(&[0x01, 0x02]).await.unwrap();
+            write_half
+        });
+        let mut received = [0u8; 2];
+        server.read_exact(&mut received).await.unwrap();
+        assert_eq!(received, [0x01, 0x02]);
+
+        server.write_all(&[0x03]).await.unwrap();
+        let mut read = [0u8; 1];
+        read_half.read_exact(&mut read).await.unwrap();
+        assert_eq!(read, [0x03]);
+
+        let write_half = writer.await.unwrap();
+        let records = receiver.try_iter().collect::<Vec<_>>();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [RecordKind::Open, RecordKind::Write, RecordKind::Read]
+        );
+        assert_eq!(records[1].message, String::from("01:02"));
+        assert_eq!(records[2].message, String::from("03"));
+        assert!(records[1..]
+            .iter()
+            .all(|record| record.label.as_deref() == Some("client")));
+
+        // Log record with drop kind is emitted only after both halves are dropped.
+        drop(read_half);
+        assert_eq!(receiver.try_iter().count(), 0);
+        drop(write_half);
+        let records = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Drop);
+    }
+
+    #[tokio::test]
+    async fn test_split_keeps_settings() {
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut logged_stream = LoggedStream::new(
+            client,
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            ChannelLogger::new(),
+        )
+        .with_keep_raw(true)
+        .with_log_eof(true);
+        let receiver = logged_stream.take_receiver_unchecked();
+        assert_eq!(receiver.try_iter().count(), 1);
+        logged_stream.set_enabled(false);
+        let (mut read_half, mut write_half) = logged_stream.split();
+        assert!(!read_half.is_enabled());
+        assert!(!write_half.is_enabled());
+
+        // Paused stream stays paused after splitting.
+        write_half.write_all(&[0x01, 0x02]).await.unwrap();
+        let mut received = [0u8; 2];
+        server.read_exact(&mut received).await.unwrap();
+        assert_eq!(receiver.try_iter().count(), 0);
+
+        // Resuming logging through one half affects both of them.
+        write_half.set_enabled(true);
+        assert!(read_half.is_enabled());
+        write_half.write_all(&[0x03]).await.unwrap();
+        server.write_all(&[0x04]).await.unwrap();
+        drop(server);
+        let mut read = Vec::new();
+        read_half.read_to_end(&mut read).await.unwrap();
+        assert_eq!(read, [0x04]);
+
+        let records = receiver.try_iter().collect::<Vec<_>>();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [RecordKind::Write, RecordKind::Read, RecordKind::Eof]
+        );
+        assert_eq!(records[0].message_bytes(), Some(&[0x03][..]));
+        assert_eq!(records[1].message_bytes(), Some(&[0x04][..]));
+
+        read_half.set_enabled(false);
+        drop(read_half);
+        drop(write_half);
+        assert_eq!(receiver.iter().count(), 0);
+    }
+}
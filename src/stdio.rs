@@ -0,0 +1,69 @@
+use crate::duplex::Duplex;
+use std::io;
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// StdioStream
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// [`Duplex`] IO object which reads from standard input stream ([`io::Stdin`]) and writes into standard output
+/// stream ([`io::Stdout`]), so it can be wrapped into [`LoggedStream`] to log traffic of CLI filters, see
+/// [`LoggedStream::stdio`] method.
+///
+/// [`LoggedStream`]: crate::LoggedStream
+/// [`LoggedStream::stdio`]: crate::LoggedStream::stdio
+pub type StdioStream = Duplex<io::Stdin, io::Stdout>;
+
+impl StdioStream {
+    /// Construct a new instance of [`StdioStream`] which reads from standard input stream and writes into
+    /// standard output stream.
+    pub fn new() -> Self {
+        Self::from_parts(io::stdin(), io::stdout())
+    }
+}
+
+impl Default for StdioStream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::filter::DefaultFilter;
+    use crate::logger::MemoryStorageLogger;
+    use crate::record::RecordKind;
+    use crate::stdio::StdioStream;
+    use crate::stream::LoggedStream;
+
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn test_unpin() {
+        assert_unpin::<StdioStream>();
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_send() {
+        assert_send::<StdioStream>();
+    }
+
+    #[test]
+    fn test_stdio_stream() {
+        let logged_stream = LoggedStream::stdio(
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Open);
+    }
+}
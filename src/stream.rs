@@ -1,17 +1,30 @@
 use crate::buffer_formatter::BufferFormatter;
+use crate::buffer_formatter::HexadecimalFormatter;
+use crate::clock::Clock;
+use crate::duplex::Duplex;
+use crate::filter::DefaultFilter;
+use crate::logger::ConsoleLogger;
 use crate::logger::Logger;
 use crate::record::Record;
 use crate::record::RecordKind;
+use crate::split;
+use crate::split::LoggedReadHalf;
+use crate::split::LoggedWriteHalf;
+use crate::stdio::StdioStream;
 use crate::ChannelLogger;
 use crate::MemoryStorageLogger;
 use crate::RecordFilter;
 use std::collections;
 use std::fmt;
 use std::io;
+use std::mem;
 use std::pin::Pin;
+use std::ptr;
+use std::sync;
 use std::sync::mpsc;
 use std::task::Context;
 use std::task::Poll;
+use std::time;
 use tokio::io as tokio_io;
 
 /// Wrapper for IO objects to log all read and write operations, errors, and drop events.
@@ -24,38 +37,88 @@ use tokio::io as tokio_io;
 ///
 /// -   Underlying IO object, which must implement [`Write`] and [`Read`] traits or their
 ///     asynchronous analogues from [`tokio`] library: [`AsyncRead`] and [`AsyncWrite`].
-/// -   Buffer formatting part, which must implement [`BufferFormatter`] trait provided by this library.
-///     This part of [`LoggedStream`] is responsible for the form you will see the input and
-///     output bytes. Currently this library provides the following implementations of [`BufferFormatter`] trait:
-///     [`LowercaseHexadecimalFormatter`], [`UppercaseHexadecimalFormatter`], [`DecimalFormatter`],
-///     [`BinaryFormatter`] and [`OctalFormatter`]. Also [`BufferFormatter`] is public trait so you are
-///     free to construct your own implementation.
-/// -   Filtering part, which must implement [`RecordFilter`] trait provide by this library.
-///     This part of [`LoggedStream`] is responsible for log records filtering. Currently this library
-///     provides the following implementation of [`RecordFilter`] trait: [`DefaultFilter`] which accepts
-///     all log records and [`RecordKindFilter`] which accepts logs with kinds specified during construct.
-///     Also [`RecordFilter`] is public trait and you are free to construct your own implementation.
-/// -   Logging part, which must implement [`Logger`] trait provided by this library. This part
-///     of [`LoggedStream`] is responsible for further work with constructed, formatter and filtered
-///     log record. For example, it can be outputted to console, written to the file, written to database,
-///     written to the memory for further use or sended by the channel. Currently this library provides
-///     the following implementations of [`Logger`] trait: [`ConsoleLogger`], [`MemoryStorageLogger`],
-///     [`ChannelLogger`] and [`FileLogger`]. Also [`Logger`] is public trait and you are free to construct
-///     your own implementation.
+/// -   Buffer formatting part, which must implement [`BufferFormatter`] trait provided by this library. This
+///     part of [`LoggedStream`] is responsible for the form you will see the input and output bytes. Currently
+///     this library provides the following implementations of [`BufferFormatter`] trait:
+///     [`HexadecimalFormatter`], [`DecimalFormatter`], [`BinaryFormatter`], [`OctalFormatter`],
+///     [`HexdumpFormatter`], [`HexAsciiFormatter`], [`CEscapeFormatter`] and `Base64Formatter` (requires
+///     `base64` feature). Output of any formatter can be limited using [`TruncatingFormatter`] wrapper and
+///     grouped into fixed-width words using [`GroupingFormatter`] wrapper, while length prefix of a frame can
+///     be separated from payload using [`PrefixSplitFormatter`] wrapper. Read and written bytes can be
+///     formatted differently using [`DirectionalFormatter`]. Also [`BufferFormatter`] is public trait so you
+///     are free to construct your own implementation or simply provide byte formatting closure to
+///     [`FnFormatter`]. Built-in formatters can be selected by name, for example from command line argument,
+///     using [`parse_formatter`] function.
+/// -   Filtering part, which must implement [`RecordFilter`] trait provide by this library. This part of
+///     [`LoggedStream`] is responsible for log records filtering. Currently this library provides the following
+///     implementations of [`RecordFilter`] trait: [`DefaultFilter`] which accepts all log records,
+///     [`RecordKindFilter`] which accepts logs with kinds specified while constructing, [`ContentFilter`] which
+///     accepts logs which message contains specified substring, [`RateLimitFilter`] which accepts at most
+///     specified number of logs per time interval, [`ProbabilisticFilter`] which accepts random fraction of
+///     logs, [`SizeFilter`] which accepts logs which payload size is within specified bounds,
+///     [`ByteValueFilter`] which accepts logs which raw payload contains any of specified byte values,
+///     [`TimeWindowFilter`] which accepts logs created within specified time window, [`DedupFilter`] which
+///     rejects consecutive duplicates of logs, [`HeadFilter`] which accepts only first specified number of logs
+///     and `RegexFilter` which accepts logs which message matches specified regular expression (requires
+///     `regex` feature). Filters can be combined using [`AndFilter`], [`OrFilter`] and [`NotFilter`]. Decisions
+///     of any filter can be counted using [`CountingFilter`] wrapper. Also [`RecordFilter`] is public trait and
+///     you are free to construct your own implementation.
+/// -   Logging part, which must implement [`Logger`] trait provided by this library. This part of
+///     [`LoggedStream`] is responsible for further work with constructed, formatter and filtered log record.
+///     For example, it can be outputted to console, written to the file, written to database, written to the
+///     memory for further use or sended by the channel. Currently this library provides the following
+///     implementations of [`Logger`] trait: [`ConsoleLogger`], [`MemoryStorageLogger`],
+///     [`SharedMemoryStorageLogger`], [`ByteBudgetLogger`], [`ChannelLogger`], [`FileLogger`], [`WriteLogger`],
+///     [`RollingFileLogger`], `GzipFileLogger` (requires `flate2` feature), `TracingLogger` (requires `tracing`
+///     feature), `StructuredLogger` (requires `log-kv` feature), `SqliteLogger` (requires `rusqlite` feature)
+///     and `SyslogLogger` (requires `syslog` feature). Records can be processed in a dedicated thread using
+///     [`BackgroundLogger`], passed to several loggers at once using [`MultiLogger`], sampled using
+///     [`SamplingLogger`], transformed using [`MapLogger`] or discarded using [`NullLogger`]. Asynchronous
+///     loggers, such as `TokioFileLogger`, implement `AsyncLogger` trait and can be used through
+///     `SpawnedLogger` which processes records in a separate task (requires `async-logger` feature). Also
+///     [`Logger`] is public trait so you are free to construct your own implementation or simply provide log
+///     record processing closure to [`FnLogger`].
 ///
 /// [`Read`]: io::Read
 /// [`Write`]: io::Write
 /// [`AsyncRead`]: tokio::io::AsyncRead
 /// [`AsyncWrite`]: tokio::io::AsyncWrite
-/// [`LowercaseHexadecimalFormatter`]: crate::LowercaseHexadecimalFormatter
-/// [`UppercaseHexadecimalFormatter`]: crate::UppercaseHexadecimalFormatter
 /// [`DecimalFormatter`]: crate::DecimalFormatter
 /// [`BinaryFormatter`]: crate::BinaryFormatter
 /// [`OctalFormatter`]: crate::OctalFormatter
-/// [`DefaultFilter`]: crate::DefaultFilter
+/// [`HexdumpFormatter`]: crate::HexdumpFormatter
+/// [`HexAsciiFormatter`]: crate::HexAsciiFormatter
+/// [`CEscapeFormatter`]: crate::CEscapeFormatter
+/// [`TruncatingFormatter`]: crate::TruncatingFormatter
+/// [`GroupingFormatter`]: crate::GroupingFormatter
+/// [`PrefixSplitFormatter`]: crate::PrefixSplitFormatter
+/// [`DirectionalFormatter`]: crate::DirectionalFormatter
+/// [`FnFormatter`]: crate::FnFormatter
+/// [`parse_formatter`]: crate::parse_formatter
 /// [`RecordKindFilter`]: crate::RecordKindFilter
-/// [`ConsoleLogger`]: crate::ConsoleLogger
+/// [`ContentFilter`]: crate::ContentFilter
+/// [`RateLimitFilter`]: crate::RateLimitFilter
+/// [`ProbabilisticFilter`]: crate::ProbabilisticFilter
+/// [`SizeFilter`]: crate::SizeFilter
+/// [`ByteValueFilter`]: crate::ByteValueFilter
+/// [`TimeWindowFilter`]: crate::TimeWindowFilter
+/// [`DedupFilter`]: crate::DedupFilter
+/// [`HeadFilter`]: crate::HeadFilter
+/// [`AndFilter`]: crate::AndFilter
+/// [`OrFilter`]: crate::OrFilter
+/// [`NotFilter`]: crate::NotFilter
+/// [`CountingFilter`]: crate::CountingFilter
+/// [`SharedMemoryStorageLogger`]: crate::SharedMemoryStorageLogger
+/// [`ByteBudgetLogger`]: crate::ByteBudgetLogger
 /// [`FileLogger`]: crate::FileLogger
+/// [`WriteLogger`]: crate::WriteLogger
+/// [`RollingFileLogger`]: crate::RollingFileLogger
+/// [`BackgroundLogger`]: crate::BackgroundLogger
+/// [`MultiLogger`]: crate::MultiLogger
+/// [`SamplingLogger`]: crate::SamplingLogger
+/// [`MapLogger`]: crate::MapLogger
+/// [`NullLogger`]: crate::NullLogger
+/// [`FnLogger`]: crate::FnLogger
 pub struct LoggedStream<
     S: 'static,
     Formatter: 'static,
@@ -66,18 +129,558 @@ pub struct LoggedStream<
     formatter: Formatter,
     filter: Filter,
     logger: L,
+    label: Option<String>,
+    keep_raw: bool,
+    log_eof: bool,
+    log_drop: bool,
+    trace_pending: bool,
+    enabled: bool,
+    clock: Option<sync::Arc<dyn Clock>>,
+    stats: Stats,
+    filled: Vec<u8>,
 }
 
 impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
     LoggedStream<S, Formatter, Filter, L>
 {
-    /// Construct a new instance of [`LoggedStream`] using provided arguments.
+    /// Construct a new instance of [`LoggedStream`] using provided arguments. Log record with [`Open`] kind is
+    /// passed to the logging part right after construction.
+    ///
+    /// [`Open`]: RecordKind::Open
     pub fn new(stream: S, formatter: Formatter, filter: Filter, logger: L) -> Self {
-        Self {
+        Self::open(stream, formatter, filter, logger, None, None)
+    }
+
+    fn open(
+        stream: S,
+        formatter: Formatter,
+        filter: Filter,
+        logger: L,
+        label: Option<String>,
+        clock: Option<sync::Arc<dyn Clock>>,
+    ) -> Self {
+        let mut logged_stream = Self {
             inner_stream: stream,
             formatter,
             filter,
             logger,
+            label,
+            keep_raw: false,
+            log_eof: false,
+            log_drop: true,
+            trace_pending: false,
+            enabled: true,
+            clock,
+            stats: Stats::default(),
+            filled: Vec::new(),
+        };
+        logged_stream.log_filtered(Record::new(RecordKind::Open, String::from("Opened.")));
+        logged_stream
+    }
+
+    /// Sets label which is attached to every log record emitted after the call, for example peer address of TCP
+    /// connection. Use [`LoggedStreamBuilder::label`] to attach it to log record with [`Open`] kind as well.
+    ///
+    /// [`Open`]: RecordKind::Open
+    pub fn with_label(mut self, label: String) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Enable or disable attaching of transferred bytes to log records with [`Read`] and [`Write`] kinds, so
+    /// they can be accessed using [`Record::message_bytes`] method and formatted again by the logging part. It is
+    /// disabled by default, since every transferred byte is copied into log record.
+    ///
+    /// [`Read`]: RecordKind::Read
+    /// [`Write`]: RecordKind::Write
+    pub fn with_keep_raw(mut self, keep_raw: bool) -> Self {
+        self.keep_raw = keep_raw;
+        self
+    }
+
+    /// Enable or disable emitting of log record with [`Eof`] kind when read operation reaches end of the
+    /// underlying IO object, that is completes with zero bytes while non-empty buffer was provided. It applies
+    /// to both [`io::Read`] and [`AsyncRead`] implementations. It is disabled by default, in this case such read
+    /// operation is logged as an empty read or not logged at all respectively.
+    ///
+    /// [`Eof`]: RecordKind::Eof
+    /// [`AsyncRead`]: tokio_io::AsyncRead
+    pub fn with_log_eof(mut self, log_eof: bool) -> Self {
+        self.log_eof = log_eof;
+        self
+    }
+
+    /// Enable or disable emitting of log record with [`Drop`] kind when [`LoggedStream`] is dropped. It is enabled
+    /// by default.
+    ///
+    /// [`Drop`]: RecordKind::Drop
+    pub fn with_log_drop(mut self, log_drop: bool) -> Self {
+        self.log_drop = log_drop;
+        self
+    }
+
+    /// Enable or disable emitting of log record with [`Pending`] kind every time asynchronous read or write
+    /// operation of the underlying IO object returns [`Poll::Pending`], which helps to find out where the stream
+    /// stalls. It is disabled by default, since such log records are very verbose.
+    ///
+    /// [`Pending`]: RecordKind::Pending
+    pub fn with_trace_pending(mut self, trace_pending: bool) -> Self {
+        self.trace_pending = trace_pending;
+        self
+    }
+
+    /// Replaces source of creation timestamps of emitted log records, which is [`SystemClock`] by default.
+    /// Provided clock is shared with clones and split halves of [`LoggedStream`]. Note that log record with
+    /// [`Open`] kind is emitted during construction, so it is stamped with system time unless the clock is
+    /// specified using [`LoggedStreamBuilder::clock`] method.
+    ///
+    /// [`SystemClock`]: crate::SystemClock
+    /// [`Open`]: RecordKind::Open
+    pub fn with_clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Some(sync::Arc::new(clock));
+        self
+    }
+
+    /// Pauses or resumes logging, for example to suppress logging of bulk transfer without swapping logging part.
+    /// While logging is paused, no log records are emitted at all, including log record with [`Drop`] kind, and
+    /// transferred bytes are not formatted, so IO operations cost almost the same as ones of the underlying IO
+    /// object. Statistics are not collected either. Logging is enabled by default.
+    ///
+    /// [`Drop`]: RecordKind::Drop
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Returns `true` if logging is not paused using [`set_enabled`] method.
+    ///
+    /// [`set_enabled`]: LoggedStream::set_enabled
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn log_pending(&mut self, operation: &str) {
+        if !self.trace_pending {
+            return;
+        }
+        self.log_filtered(Record::new(
+            RecordKind::Pending,
+            format!("Pending {operation}."),
+        ));
+    }
+
+    fn log_end_of_stream(&mut self) {
+        self.log_filtered(Record::new(
+            RecordKind::Eof,
+            String::from("Reached end of stream."),
+        ));
+    }
+
+    /// Returns label attached to emitted log records, if any.
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns a snapshot of statistics collected since construction. Statistics take into account all log
+    /// records, including ones rejected by filtering part.
+    #[inline]
+    pub fn stats(&self) -> Stats {
+        self.stats.clone()
+    }
+
+    fn log_filtered(&mut self, mut record: Record) {
+        if !self.enabled {
+            return;
+        }
+        self.stats.update(&record);
+        record.stamp(self.label.as_deref(), self.clock.as_deref());
+        if self.filter.check(&record) {
+            self.logger.log(record);
+        }
+    }
+
+    fn log_unfiltered(&mut self, record: Record) {
+        if !self.enabled {
+            return;
+        }
+        log_unfiltered_parts(
+            record,
+            &mut self.stats,
+            self.label.as_deref(),
+            self.clock.as_deref(),
+            &mut self.logger,
+        );
+    }
+
+    /// Emits log record with [`Connect`] kind containing provided remote address. It is intended to annotate
+    /// wrapped freshly-connected sockets with their endpoint, while log record with [`Open`] kind describes only
+    /// wrapper lifecycle.
+    ///
+    /// [`Connect`]: RecordKind::Connect
+    /// [`Open`]: RecordKind::Open
+    pub fn log_connect(&mut self, address: impl fmt::Display) {
+        self.log_filtered(Record::new(
+            RecordKind::Connect,
+            format!("Connected to {address}."),
+        ));
+    }
+
+    /// Returns a shared reference to the underlying IO object.
+    #[inline]
+    pub fn get_ref(&self) -> &S {
+        &self.inner_stream
+    }
+
+    /// Returns a mutable reference to the underlying IO object. Note that reading or writing through this
+    /// reference bypasses logging.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner_stream
+    }
+
+    /// Replaces formatting part of [`LoggedStream`] in place. Affects only log records constructed after the call.
+    ///
+    /// Pinned stream (for example during asynchronous usage) can be modified through [`Pin::get_mut`], which
+    /// requires all parts to be [`Unpin`]. This is already required by [`AsyncRead`] and [`AsyncWrite`]
+    /// implementations of [`LoggedStream`].
+    ///
+    /// [`AsyncRead`]: tokio::io::AsyncRead
+    /// [`AsyncWrite`]: tokio::io::AsyncWrite
+    #[inline]
+    pub fn set_formatter(&mut self, formatter: Formatter) {
+        self.formatter = formatter;
+    }
+
+    /// Replaces filtering part of [`LoggedStream`] in place. Affects only log records constructed after the call.
+    ///
+    /// See [`set_formatter`] for notes about pinned streams.
+    ///
+    /// [`set_formatter`]: LoggedStream::set_formatter
+    #[inline]
+    pub fn set_filter(&mut self, filter: Filter) {
+        self.filter = filter;
+    }
+
+    /// Replaces logging part of [`LoggedStream`] in place. Previous logging part is dropped.
+    ///
+    /// See [`set_formatter`] for notes about pinned streams.
+    ///
+    /// [`set_formatter`]: LoggedStream::set_formatter
+    #[inline]
+    pub fn set_logger(&mut self, logger: L) {
+        self.logger = logger;
+    }
+
+    /// Consumes [`LoggedStream`] and returns the underlying IO object. Formatting, filtering and logging parts are
+    /// dropped and log record with [`Drop`] kind is not emitted, since the underlying IO object is not
+    /// deallocated.
+    ///
+    /// [`Drop`]: RecordKind::Drop
+    pub fn into_inner(self) -> S {
+        self.into_raw_parts().0
+    }
+
+    /// Consumes [`LoggedStream`] and returns all four parts it was constructed from: the underlying IO object,
+    /// formatting, filtering and logging parts, so they can be reused, for example to rebuild [`LoggedStream`]
+    /// with different configuration. Log record with [`Drop`] kind is not emitted.
+    ///
+    /// [`Drop`]: RecordKind::Drop
+    pub fn into_parts(self) -> (S, Formatter, Filter, L) {
+        let (stream, formatter, filter, logger, ..) = self.into_raw_parts();
+        (stream, formatter, filter, logger)
+    }
+
+    /// Moves all parts out of [`LoggedStream`] without running its [`Drop`] implementation.
+    #[allow(clippy::type_complexity)]
+    fn into_raw_parts(
+        self,
+    ) -> (
+        S,
+        Formatter,
+        Filter,
+        L,
+        Option<String>,
+        Option<sync::Arc<dyn Clock>>,
+    ) {
+        let this = mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never used again and never dropped, so every field is moved out exactly once.
+        unsafe {
+            let _ = ptr::read(&this.stats);
+            let _ = ptr::read(&this.filled);
+            (
+                ptr::read(&this.inner_stream),
+                ptr::read(&this.formatter),
+                ptr::read(&this.filter),
+                ptr::read(&this.logger),
+                ptr::read(&this.label),
+                ptr::read(&this.clock),
+            )
+        }
+    }
+}
+
+impl<
+        S: tokio_io::AsyncRead + tokio_io::AsyncWrite + 'static,
+        Formatter: BufferFormatter + 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > LoggedStream<S, Formatter, Filter, L>
+{
+    /// Splits [`LoggedStream`] into independent read and write halves, which can be used concurrently, for
+    /// example from different tasks. See [`LoggedReadHalf`] for details about synchronization between halves.
+    /// Label, clock and all other settings, including paused logging, are carried over to both halves.
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        self,
+    ) -> (
+        LoggedReadHalf<S, Formatter, Filter, L>,
+        LoggedWriteHalf<S, Formatter, Filter, L>,
+    ) {
+        let (keep_raw, log_eof, log_drop, trace_pending, enabled) = (
+            self.keep_raw,
+            self.log_eof,
+            self.log_drop,
+            self.trace_pending,
+            self.enabled,
+        );
+        let (stream, formatter, filter, logger, label, clock) = self.into_raw_parts();
+        let settings = split::Settings {
+            label,
+            clock,
+            keep_raw,
+            log_eof,
+            log_drop,
+            trace_pending,
+            enabled,
+        };
+        split::split(stream, formatter, filter, logger, settings)
+    }
+}
+
+impl<Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedStream<StdioStream, Formatter, Filter, L>
+{
+    /// Construct a new instance of [`LoggedStream`] which reads from standard input stream and writes into
+    /// standard output stream using [`StdioStream`] as underlying IO object.
+    pub fn stdio(formatter: Formatter, filter: Filter, logger: L) -> Self {
+        Self::new(StdioStream::new(), formatter, filter, logger)
+    }
+}
+
+impl<
+        R: 'static,
+        W: 'static,
+        Formatter: 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > LoggedDuplex<R, W, Formatter, Filter, L>
+{
+    /// Construct a new instance of [`LoggedDuplex`] which reads from provided reader and writes into provided
+    /// writer, so traffic of a transport which exposes separate halves instead of a single duplex object is
+    /// logged by the same formatting, filtering and logging parts.
+    pub fn duplex(reader: R, writer: W, formatter: Formatter, filter: Filter, logger: L) -> Self {
+        Self::new(
+            Duplex::from_parts(reader, writer),
+            formatter,
+            filter,
+            logger,
+        )
+    }
+}
+
+/// [`LoggedStream`] over distinct reader and writer objects joined by [`Duplex`]. Read operations are
+/// delegated to the reader and write operations to the writer, both synchronous and asynchronous ones, while all
+/// of them are logged by shared formatting, filtering and logging parts. It can be constructed using
+/// [`LoggedStream::duplex`] method.
+pub type LoggedDuplex<R, W, Formatter, Filter, L> =
+    LoggedStream<Duplex<R, W>, Formatter, Filter, L>;
+
+/// Builder of [`LoggedStream`] which allows to specify its parts one by one using chainable methods.
+///
+/// Any part which was not specified explicitly is defaulted during [`build`]: [`HexadecimalFormatter::new_default`]
+/// is used as formatting part, [`DefaultFilter`] as filtering part and [`ConsoleLogger`] with `debug` level as
+/// logging part.
+///
+/// [`build`]: LoggedStreamBuilder::build
+pub struct LoggedStreamBuilder<
+    S: 'static,
+    Formatter: 'static = HexadecimalFormatter,
+    Filter: RecordFilter + 'static = DefaultFilter,
+    L: Logger + 'static = ConsoleLogger,
+> {
+    stream: S,
+    formatter: Formatter,
+    filter: Filter,
+    logger: L,
+    label: Option<String>,
+    clock: Option<sync::Arc<dyn Clock>>,
+}
+
+impl<S: 'static> LoggedStreamBuilder<S> {
+    /// Construct a new instance of [`LoggedStreamBuilder`] using provided underlying IO object. All other parts
+    /// are set to defaults.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            formatter: HexadecimalFormatter::new_default(),
+            filter: DefaultFilter,
+            logger: ConsoleLogger::new_unchecked("debug"),
+            label: None,
+            clock: None,
+        }
+    }
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static>
+    LoggedStreamBuilder<S, Formatter, Filter, L>
+{
+    /// Replace formatting part of the future [`LoggedStream`].
+    pub fn formatter<F: 'static>(self, formatter: F) -> LoggedStreamBuilder<S, F, Filter, L> {
+        LoggedStreamBuilder {
+            stream: self.stream,
+            formatter,
+            filter: self.filter,
+            logger: self.logger,
+            label: self.label,
+            clock: self.clock,
+        }
+    }
+
+    /// Replace filtering part of the future [`LoggedStream`].
+    pub fn filter<F: RecordFilter + 'static>(
+        self,
+        filter: F,
+    ) -> LoggedStreamBuilder<S, Formatter, F, L> {
+        LoggedStreamBuilder {
+            stream: self.stream,
+            formatter: self.formatter,
+            filter,
+            logger: self.logger,
+            label: self.label,
+            clock: self.clock,
+        }
+    }
+
+    /// Replace logging part of the future [`LoggedStream`].
+    pub fn logger<T: Logger + 'static>(
+        self,
+        logger: T,
+    ) -> LoggedStreamBuilder<S, Formatter, Filter, T> {
+        LoggedStreamBuilder {
+            stream: self.stream,
+            formatter: self.formatter,
+            filter: self.filter,
+            logger,
+            label: self.label,
+            clock: self.clock,
+        }
+    }
+
+    /// Set label of the future [`LoggedStream`]. See [`LoggedStream::with_label`] for details.
+    pub fn label(mut self, label: String) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// Set source of log record timestamps of the future [`LoggedStream`]. Unlike [`LoggedStream::with_clock`]
+    /// method, it applies to log record with [`Open`] kind as well.
+    ///
+    /// [`Open`]: RecordKind::Open
+    pub fn clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Some(sync::Arc::new(clock));
+        self
+    }
+
+    /// Construct [`LoggedStream`] from specified parts. It behaves exactly like [`LoggedStream::new`].
+    pub fn build(self) -> LoggedStream<S, Formatter, Filter, L> {
+        LoggedStream::open(
+            self.stream,
+            self.formatter,
+            self.filter,
+            self.logger,
+            self.label,
+            self.clock,
+        )
+    }
+}
+
+impl<
+        S: fmt::Debug + 'static,
+        Formatter: fmt::Debug + 'static,
+        Filter: RecordFilter + fmt::Debug + 'static,
+        L: Logger + fmt::Debug + 'static,
+    > fmt::Debug for LoggedStreamBuilder<S, Formatter, Filter, L>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggedStreamBuilder")
+            .field("stream", &self.stream)
+            .field("formatter", &self.formatter)
+            .field("filter", &self.filter)
+            .field("logger", &self.logger)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+/// Snapshot of [`LoggedStream`] statistics returned by [`LoggedStream::stats`] method.
+///
+/// It contains the number of log records of each kind ([`RecordKind`]) and the total number of bytes transferred
+/// by read and write operations.
+///
+/// When `metrics` feature is enabled, the same values are also reported to the installed [`metrics`] recorder as
+/// `logged_stream_records_total` counter labeled by record kind, `logged_stream_bytes_read_total` and
+/// `logged_stream_bytes_written_total` counters, so they are aggregated across all [`LoggedStream`] instances.
+///
+/// [`metrics`]: https://docs.rs/metrics
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    record_counts: [u64; RecordKind::ALL.len()],
+    bytes_read: u64,
+    bytes_written: u64,
+}
+
+impl Stats {
+    /// Returns the number of log records with provided kind.
+    #[inline]
+    pub fn record_count(&self, kind: RecordKind) -> u64 {
+        self.record_counts[kind.index()]
+    }
+
+    /// Returns the total number of bytes read from the underlying IO object.
+    #[inline]
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Returns the total number of bytes written into the underlying IO object.
+    #[inline]
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    fn update(&mut self, record: &Record) {
+        self.record_counts[record.kind.index()] += 1;
+        let byte_count = record.byte_count.unwrap_or_default() as u64;
+        match record.kind {
+            RecordKind::Read => self.bytes_read += byte_count,
+            RecordKind::Write => self.bytes_written += byte_count,
+            _ => {}
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter!("logged_stream_records_total", "kind" => record.kind.name())
+                .increment(1);
+            match record.kind {
+                RecordKind::Read => {
+                    metrics::counter!("logged_stream_bytes_read_total").increment(byte_count)
+                }
+                RecordKind::Write => {
+                    metrics::counter!("logged_stream_bytes_written_total").increment(byte_count)
+                }
+                _ => {}
+            }
         }
     }
 }
@@ -94,6 +697,11 @@ impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static>
     pub fn clear_log_records(&mut self) {
         self.logger.clear_log_records()
     }
+
+    #[inline]
+    pub fn drain_log_records(&mut self) -> collections::VecDeque<Record> {
+        self.logger.drain_log_records()
+    }
 }
 
 impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static>
@@ -123,10 +731,46 @@ impl<
             .field("formatter", &self.formatter)
             .field("filter", &self.filter)
             .field("logger", &self.logger)
+            .field("label", &self.label)
             .finish()
     }
 }
 
+/// Clones every part of [`LoggedStream`] into a new independent instance, which emits its own log record with
+/// [`Open`] kind right after cloning and collects its own statistics. Among loggers provided by this library
+/// [`ConsoleLogger`], [`MemoryStorageLogger`], [`NullLogger`], `TracingLogger` and `SpawnedLogger` implement
+/// [`Clone`] trait, while [`ChannelLogger`] doesn't, since its receiver can't be shared.
+///
+/// [`Open`]: RecordKind::Open
+/// [`ConsoleLogger`]: crate::ConsoleLogger
+/// [`MemoryStorageLogger`]: crate::MemoryStorageLogger
+/// [`NullLogger`]: crate::NullLogger
+/// [`ChannelLogger`]: crate::ChannelLogger
+impl<
+        S: Clone + 'static,
+        Formatter: Clone + 'static,
+        Filter: RecordFilter + Clone + 'static,
+        L: Logger + Clone + 'static,
+    > Clone for LoggedStream<S, Formatter, Filter, L>
+{
+    fn clone(&self) -> Self {
+        let mut logged_stream = Self::open(
+            self.inner_stream.clone(),
+            self.formatter.clone(),
+            self.filter.clone(),
+            self.logger.clone(),
+            self.label.clone(),
+            self.clock.clone(),
+        )
+        .with_keep_raw(self.keep_raw)
+        .with_log_eof(self.log_eof)
+        .with_log_drop(self.log_drop)
+        .with_trace_pending(self.trace_pending);
+        logged_stream.set_enabled(self.enabled);
+        logged_stream
+    }
+}
+
 impl<
         S: io::Read + 'static,
         Formatter: BufferFormatter + 'static,
@@ -135,22 +779,18 @@ impl<
     > io::Read for LoggedStream<S, Formatter, Filter, L>
 {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let started = time::Instant::now();
         let result = self.inner_stream.read(buf);
+        let elapsed = started.elapsed();
 
         match &result {
-            Ok(length) => {
-                let record = Record::new(
-                    RecordKind::Read,
-                    self.formatter.format_buffer(&buf[0..*length]),
-                );
-                if self.filter.check(&record) {
-                    self.logger.log(record);
-                }
-            }
+            Ok(0) if self.log_eof && !buf.is_empty() => self.log_end_of_stream(),
+            Ok(length) => self.log_transfer(RecordKind::Read, &buf[0..*length], Some(elapsed)),
             Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock) => {}
-            Err(e) => self.logger.log(Record::new(
+            Err(e) => self.log_unfiltered(Record::with_io_error(
                 RecordKind::Error,
                 format!("Error during read: {e}"),
+                e,
             )),
         };
 
@@ -171,29 +811,30 @@ impl<
         buf: &mut tokio_io::ReadBuf<'_>,
     ) -> Poll<io::Result<()>> {
         let mut_self = self.get_mut();
+        let requested = buf.remaining();
         let length_before_read = buf.filled().len();
+        let started = time::Instant::now();
         let result = Pin::new(&mut mut_self.inner_stream).poll_read(cx, buf);
+        let elapsed = started.elapsed();
         let length_after_read = buf.filled().len();
         let diff = length_after_read - length_before_read;
 
         match &result {
-            Poll::Ready(Ok(())) if diff == 0 => {}
-            Poll::Ready(Ok(())) => {
-                let record = Record::new(
-                    RecordKind::Read,
-                    mut_self
-                        .formatter
-                        .format_buffer(&(buf.filled())[length_before_read..length_after_read]),
-                );
-                if mut_self.filter.check(&record) {
-                    mut_self.logger.log(record);
-                }
+            Poll::Ready(Ok(())) if diff == 0 && mut_self.log_eof && requested != 0 => {
+                mut_self.log_end_of_stream()
             }
-            Poll::Ready(Err(e)) => mut_self.logger.log(Record::new(
+            Poll::Ready(Ok(())) if diff == 0 => {}
+            Poll::Ready(Ok(())) => mut_self.log_transfer(
+                RecordKind::Read,
+                &(buf.filled())[length_before_read..length_after_read],
+                Some(elapsed),
+            ),
+            Poll::Ready(Err(e)) => mut_self.log_unfiltered(Record::with_io_error(
                 RecordKind::Error,
                 format!("Error during async read: {e}"),
+                e,
             )),
-            Poll::Pending => {}
+            Poll::Pending => mut_self.log_pending("read"),
         }
 
         result
@@ -208,26 +849,21 @@ impl<
     > io::Write for LoggedStream<S, Formatter, Filter, L>
 {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let started = time::Instant::now();
         let result = self.inner_stream.write(buf);
+        let elapsed = started.elapsed();
 
         match &result {
-            Ok(length) => {
-                let record = Record::new(
-                    RecordKind::Write,
-                    self.formatter.format_buffer(&buf[0..*length]),
-                );
-                if self.filter.check(&record) {
-                    self.logger.log(record);
-                }
-            }
+            Ok(length) => self.log_write(buf, *length, Some(elapsed)),
             Err(e)
                 if matches!(
                     e.kind(),
                     io::ErrorKind::WriteZero | io::ErrorKind::WouldBlock
                 ) => {}
-            Err(e) => self.logger.log(Record::new(
+            Err(e) => self.log_unfiltered(Record::with_io_error(
                 RecordKind::Error,
                 format!("Error during write: {e}"),
+                e,
             )),
         };
 
@@ -235,68 +871,1448 @@ impl<
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.inner_stream.flush()
+        let result = self.inner_stream.flush();
+        match &result {
+            Ok(()) => self.log_filtered(Record::new(
+                RecordKind::Flush,
+                String::from("Flush request."),
+            )),
+            Err(e) => self.log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during flush: {e}"),
+                e,
+            )),
+        }
+        result
     }
 }
 
 impl<
-        S: tokio_io::AsyncWrite + Unpin + 'static,
-        Formatter: BufferFormatter + Unpin + 'static,
-        Filter: RecordFilter + Unpin + 'static,
-        L: Logger + Unpin + 'static,
-    > tokio_io::AsyncWrite for LoggedStream<S, Formatter, Filter, L>
+        S: 'static,
+        Formatter: BufferFormatter + 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > LoggedStream<S, Formatter, Filter, L>
 {
-    fn poll_write(
-        self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        buf: &[u8],
-    ) -> Poll<Result<usize, io::Error>> {
-        let mut_self = self.get_mut();
-        let result = Pin::new(&mut mut_self.inner_stream).poll_write(cx, buf);
+    /// Logs bytes which are consumed from the underlying buffered reader as log record with [`Read`] kind. Bytes
+    /// are taken from the ones remembered by [`remember_filled`] function, the underlying IO object is not
+    /// accessed.
+    ///
+    /// [`Read`]: RecordKind::Read
+    fn log_consumed(&mut self, amount: usize) {
+        let amount = amount.min(self.filled.len());
+        if amount == 0 {
+            return;
+        }
+        if self.enabled {
+            let record = transfer_record(
+                &self.formatter,
+                self.keep_raw,
+                RecordKind::Read,
+                &self.filled[..amount],
+                None,
+            );
+            self.log_filtered(record);
+        }
+        self.filled.drain(..amount);
+    }
+
+    /// Logs transferred bytes as log record with provided kind unless logging is paused, in which case bytes are
+    /// not even formatted.
+    fn log_transfer(&mut self, kind: RecordKind, buffer: &[u8], duration: Option<time::Duration>) {
+        if !self.enabled {
+            return;
+        }
+        let record = transfer_record(&self.formatter, self.keep_raw, kind, buffer, duration);
+        self.log_filtered(record);
+    }
+
+    /// Logs first `length` bytes of provided buffer as log record with [`Write`] kind unless logging is paused.
+    /// In case if fewer bytes than provided were written, log record is marked as partial write.
+    ///
+    /// [`Write`]: RecordKind::Write
+    fn log_write(&mut self, buf: &[u8], length: usize, duration: Option<time::Duration>) {
+        if !self.enabled {
+            return;
+        }
+        let mut record = transfer_record(
+            &self.formatter,
+            self.keep_raw,
+            RecordKind::Write,
+            &buf[0..length],
+            duration,
+        );
+        record.mark_partial_write(length, buf.len());
+        self.log_filtered(record);
+    }
+}
+
+/// Constructs log record with provided kind describing transferred bytes and duration of the underlying IO call.
+/// Transferred bytes are attached to log record only in case if it was enabled using
+/// [`LoggedStream::with_keep_raw`] method. Parts are passed separately, so it can be called while the underlying IO
+/// object is borrowed, for example by buffer returned from [`io::BufRead::fill_buf`].
+fn transfer_record<Formatter: BufferFormatter>(
+    formatter: &Formatter,
+    keep_raw: bool,
+    kind: RecordKind,
+    buffer: &[u8],
+    duration: Option<time::Duration>,
+) -> Record {
+    let message = formatter.format_buffer_with_kind(kind, buffer);
+    let record = if keep_raw {
+        Record::with_raw(kind, message, buffer.to_vec())
+    } else {
+        Record::with_byte_count(kind, message, buffer.len())
+    };
+    Record { duration, ..record }
+}
+
+/// Updates statistics, stamps log record and passes it to logging part bypassing filtering part. Parts are passed
+/// separately, so it can be called while the underlying IO object is borrowed.
+fn log_unfiltered_parts<L: Logger>(
+    mut record: Record,
+    stats: &mut Stats,
+    label: Option<&str>,
+    clock: Option<&dyn Clock>,
+    logger: &mut L,
+) {
+    stats.update(&record);
+    record.stamp(label, clock);
+    logger.log(record);
+}
+
+/// Remembers bytes returned from the underlying buffered reader, so they can be logged when consumed. Buffered
+/// reader returns not consumed bytes again until they are consumed, so only bytes following already remembered
+/// ones are copied and every byte is copied once.
+fn remember_filled(remembered: &mut Vec<u8>, filled: &[u8]) {
+    if filled.len() < remembered.len() {
+        remembered.clear();
+    }
+    let known = remembered.len();
+    remembered.extend_from_slice(&filled[known..]);
+}
+
+impl<
+        S: io::BufRead + 'static,
+        Formatter: BufferFormatter + 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > io::BufRead for LoggedStream<S, Formatter, Filter, L>
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let result = self.inner_stream.fill_buf();
         match &result {
-            Poll::Ready(Ok(length)) => {
-                let record = Record::new(
-                    RecordKind::Write,
-                    mut_self.formatter.format_buffer(&buf[0..*length]),
+            Ok(filled) if self.enabled => remember_filled(&mut self.filled, filled),
+            Err(e) if self.enabled && !matches!(e.kind(), io::ErrorKind::WouldBlock) => {
+                // Parts are accessed directly, since the underlying IO object is still borrowed by result.
+                log_unfiltered_parts(
+                    Record::with_io_error(RecordKind::Error, format!("Error during read: {e}"), e),
+                    &mut self.stats,
+                    self.label.as_deref(),
+                    self.clock.as_deref(),
+                    &mut self.logger,
                 );
-                if mut_self.filter.check(&record) {
-                    mut_self.logger.log(record);
-                }
             }
-            Poll::Ready(Err(e)) => mut_self.logger.log(Record::new(
-                RecordKind::Error,
-                format!("Error during async write: {e}"),
-            )),
-            Poll::Pending => {}
+            _ => {}
         }
         result
     }
 
-    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
-        Pin::new(&mut self.get_mut().inner_stream).poll_flush(cx)
+    fn consume(&mut self, amount: usize) {
+        self.log_consumed(amount);
+        self.inner_stream.consume(amount)
     }
+}
 
-    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+impl<
+        S: tokio_io::AsyncBufRead + Unpin + 'static,
+        Formatter: BufferFormatter + Unpin + 'static,
+        Filter: RecordFilter + Unpin + 'static,
+        L: Logger + Unpin + 'static,
+    > tokio_io::AsyncBufRead for LoggedStream<S, Formatter, Filter, L>
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
         let mut_self = self.get_mut();
-        let result = Pin::new(&mut mut_self.inner_stream).poll_shutdown(cx);
-        let record = Record::new(
-            RecordKind::Shutdown,
-            String::from("Writer shutdown request."),
-        );
-        if mut_self.filter.check(&record) {
-            mut_self.logger.log(record);
+        let result = Pin::new(&mut mut_self.inner_stream).poll_fill_buf(cx);
+        match &result {
+            Poll::Ready(Ok(filled)) if mut_self.enabled => {
+                remember_filled(&mut mut_self.filled, filled)
+            }
+            Poll::Ready(Err(e)) if mut_self.enabled => {
+                // Parts are accessed directly, since the underlying IO object is still borrowed by result.
+                log_unfiltered_parts(
+                    Record::with_io_error(
+                        RecordKind::Error,
+                        format!("Error during async read: {e}"),
+                        e,
+                    ),
+                    &mut mut_self.stats,
+                    mut_self.label.as_deref(),
+                    mut_self.clock.as_deref(),
+                    &mut mut_self.logger,
+                );
+            }
+            _ => {}
         }
         result
     }
+
+    fn consume(self: Pin<&mut Self>, amount: usize) {
+        let mut_self = self.get_mut();
+        mut_self.log_consumed(amount);
+        Pin::new(&mut mut_self.inner_stream).consume(amount)
+    }
 }
 
-impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> Drop
-    for LoggedStream<S, Formatter, Filter, L>
+impl<
+        S: io::Seek + 'static,
+        Formatter: 'static,
+        Filter: RecordFilter + 'static,
+        L: Logger + 'static,
+    > io::Seek for LoggedStream<S, Formatter, Filter, L>
+{
+    fn seek(&mut self, position: io::SeekFrom) -> io::Result<u64> {
+        let result = self.inner_stream.seek(position);
+        match &result {
+            Ok(offset) => {
+                self.log_filtered(Record::new(RecordKind::Seek, format!("Seek to {offset}.")))
+            }
+            Err(e) => self.log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during seek: {e}"),
+                e,
+            )),
+        }
+        result
+    }
+}
+
+impl<
+        S: tokio_io::AsyncWrite + Unpin + 'static,
+        Formatter: BufferFormatter + Unpin + 'static,
+        Filter: RecordFilter + Unpin + 'static,
+        L: Logger + Unpin + 'static,
+    > tokio_io::AsyncWrite for LoggedStream<S, Formatter, Filter, L>
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, io::Error>> {
+        let mut_self = self.get_mut();
+        let started = time::Instant::now();
+        let result = Pin::new(&mut mut_self.inner_stream).poll_write(cx, buf);
+        let elapsed = started.elapsed();
+        match &result {
+            Poll::Ready(Ok(length)) => mut_self.log_write(buf, *length, Some(elapsed)),
+            Poll::Ready(Err(e)) => mut_self.log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during async write: {e}"),
+                e,
+            )),
+            Poll::Pending => mut_self.log_pending("write"),
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let mut_self = self.get_mut();
+        let result = Pin::new(&mut mut_self.inner_stream).poll_flush(cx);
+        match &result {
+            Poll::Ready(Ok(())) => mut_self.log_filtered(Record::new(
+                RecordKind::Flush,
+                String::from("Flush request."),
+            )),
+            Poll::Ready(Err(e)) => mut_self.log_unfiltered(Record::with_io_error(
+                RecordKind::Error,
+                format!("Error during async flush: {e}"),
+                e,
+            )),
+            Poll::Pending => {}
+        }
+        result
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        let mut_self = self.get_mut();
+        let result = Pin::new(&mut mut_self.inner_stream).poll_shutdown(cx);
+        let record = Record::new(
+            RecordKind::Shutdown,
+            String::from("Writer shutdown request."),
+        );
+        mut_self.log_filtered(record);
+        result
+    }
+}
+
+impl<S: 'static, Formatter: 'static, Filter: RecordFilter + 'static, L: Logger + 'static> Drop
+    for LoggedStream<S, Formatter, Filter, L>
 {
     fn drop(&mut self) {
+        if !self.log_drop {
+            return;
+        }
         let record = Record::new(RecordKind::Drop, String::from("Deallocated."));
-        if self.filter.check(&record) {
-            self.logger.log(record);
+        self.log_filtered(record);
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer_formatter::BufferFormatter;
+    use crate::buffer_formatter::DecimalFormatter;
+    use crate::buffer_formatter::DirectionalFormatter;
+    use crate::buffer_formatter::HexCase;
+    use crate::buffer_formatter::HexadecimalFormatter;
+    use crate::buffer_formatter::TruncatingFormatter;
+    use crate::clock::Clock;
+    use crate::filter::DefaultFilter;
+    use crate::filter::RecordFilter;
+    use crate::filter::RecordKindFilter;
+    use crate::logger::ChannelLogger;
+    use crate::logger::ConsoleLogger;
+    use crate::logger::MemoryStorageLogger;
+    use crate::record::Record;
+    use crate::record::RecordKind;
+    use crate::stream::LoggedStream;
+    use crate::stream::LoggedStreamBuilder;
+    use crate::stream::Stats;
+    use chrono::DateTime;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use std::collections;
+    use std::io;
+    use std::io::Read;
+    use std::io::Write;
+    use std::pin::Pin;
+    use std::task::Context;
+    use std::task::Poll;
+
+    fn assert_unpin<T: Unpin>() {}
+
+    #[test]
+    fn test_unpin() {
+        assert_unpin::<
+            LoggedStream<tokio::net::TcpStream, HexadecimalFormatter, DefaultFilter, ConsoleLogger>,
+        >();
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_send() {
+        // Required to serve logged connections from spawned tasks, for example by hyper.
+        assert_send::<
+            LoggedStream<tokio::net::TcpStream, HexadecimalFormatter, DefaultFilter, ConsoleLogger>,
+        >();
+        assert_send::<
+            LoggedStream<
+                std::net::TcpStream,
+                HexadecimalFormatter,
+                DefaultFilter,
+                MemoryStorageLogger,
+            >,
+        >();
+    }
+
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_sync() {
+        assert_sync::<
+            LoggedStream<tokio::net::TcpStream, HexadecimalFormatter, DefaultFilter, ConsoleLogger>,
+        >();
+        assert_sync::<
+            LoggedStream<
+                std::net::TcpStream,
+                HexadecimalFormatter,
+                DefaultFilter,
+                MemoryStorageLogger,
+            >,
+        >();
+    }
+
+    #[test]
+    fn test_open_record() {
+        let logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Open);
+
+        let logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read]),
+            MemoryStorageLogger::new(100),
+        );
+        assert!(logged_stream.get_log_records().is_empty());
+    }
+
+    #[test]
+    fn test_byte_count() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+
+        let written = [0x01, 0x02, 0x03, 0x04, 0x05];
+        logged_stream.write_all(&written).unwrap();
+        logged_stream.inner_stream.set_position(1);
+        let mut read = [0u8; 3];
+        logged_stream.read_exact(&mut read).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[0].message, String::from("01:02:03:04:05"));
+        assert_eq!(records[0].byte_count, Some(written.len()));
+        assert_eq!(records[1].kind, RecordKind::Read);
+        assert_eq!(records[1].message, String::from("02:03:04"));
+        assert_eq!(records[1].byte_count, Some(read.len()));
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let logged_stream = LoggedStreamBuilder::new(io::Cursor::new(Vec::<u8>::new()))
+            .formatter(DecimalFormatter::new(Some("-")))
+            .build();
+        assert_eq!(
+            format!("{logged_stream:?}"),
+            format!(
+                "LoggedStream {{ inner_stream: {:?}, formatter: {:?}, filter: {:?}, logger: {:?}, label: None }}",
+                io::Cursor::new(Vec::<u8>::new()),
+                DecimalFormatter::new(Some("-")),
+                DefaultFilter,
+                ConsoleLogger::new_unchecked("debug"),
+            )
+        );
+
+        let mut logged_stream = LoggedStreamBuilder::new(io::Cursor::new(Vec::<u8>::new()))
+            .logger(MemoryStorageLogger::new(100))
+            .build();
+        logged_stream.write_all(&[0x0a, 0xff]).unwrap();
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_eq!(records[1].message, String::from("0a:ff"));
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            ChannelLogger::new(),
+        );
+        let receiver = logged_stream.take_receiver_unchecked();
+
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+        logged_stream.get_mut().write_all(&[0x03]).unwrap();
+        assert_eq!(logged_stream.get_ref().get_ref(), &vec![0x01, 0x02, 0x03]);
+
+        let inner = logged_stream.into_inner();
+        assert_eq!(inner.into_inner(), vec![0x01, 0x02, 0x03]);
+
+        let kinds: Vec<RecordKind> = receiver.iter().map(|record| record.kind).collect();
+        assert_eq!(kinds, vec![RecordKind::Open, RecordKind::Write]);
+    }
+
+    #[test]
+    fn test_into_parts() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+
+        let (stream, formatter, filter, logger) = logged_stream.into_parts();
+        let kinds: Vec<RecordKind> = logger
+            .get_log_records()
+            .iter()
+            .map(|record| record.kind)
+            .collect();
+        assert_eq!(kinds, vec![RecordKind::Open, RecordKind::Write]);
+
+        // Parts are reused to rebuild stream which keeps accumulating log records in the same logger.
+        let mut logged_stream = LoggedStream::new(stream, formatter, filter, logger);
+        logged_stream.write_all(&[0x03]).unwrap();
+        let (stream, _, _, logger) = logged_stream.into_parts();
+        assert_eq!(stream.into_inner(), vec![0x01, 0x02, 0x03]);
+        let kinds: Vec<RecordKind> = logger
+            .get_log_records()
+            .iter()
+            .map(|record| record.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                RecordKind::Open,
+                RecordKind::Write,
+                RecordKind::Open,
+                RecordKind::Write
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_filter() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            Box::new(DefaultFilter) as Box<dyn RecordFilter>,
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.write_all(&[0x01]).unwrap();
+        logged_stream.flush().unwrap();
+        assert_eq!(logged_stream.drain_log_records().len(), 3);
+
+        logged_stream.set_filter(Box::new(RecordKindFilter::new(&[RecordKind::Write])));
+        logged_stream.write_all(&[0x02]).unwrap();
+        logged_stream.flush().unwrap();
+        let records = logged_stream.drain_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[0].message, String::from("02"));
+
+        logged_stream.set_formatter(HexadecimalFormatter::new(Some("-"), HexCase::Upper));
+        logged_stream.set_logger(MemoryStorageLogger::new(100));
+        logged_stream.write_all(&[0x0a, 0x0b]).unwrap();
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, String::from("0A-0B"));
+    }
+
+    #[test]
+    fn test_label() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        )
+        .with_label(String::from("127.0.0.1:8080"));
+        assert_eq!(logged_stream.label(), Some("127.0.0.1:8080"));
+
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+        logged_stream.get_mut().set_position(0);
+        let mut read = [0u8; 2];
+        logged_stream.read_exact(&mut read).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_eq!(records[0].label, None);
+        assert_eq!(records[1].kind, RecordKind::Write);
+        assert_eq!(records[1].label.as_deref(), Some("127.0.0.1:8080"));
+        assert_eq!(records[2].kind, RecordKind::Read);
+        assert_eq!(records[2].label.as_deref(), Some("127.0.0.1:8080"));
+
+        let logged_stream = LoggedStreamBuilder::new(io::Cursor::new(Vec::<u8>::new()))
+            .label(String::from("peer"))
+            .logger(MemoryStorageLogger::new(100))
+            .build();
+        let records = logged_stream.get_log_records();
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_eq!(records[0].label.as_deref(), Some("peer"));
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[]),
+            MemoryStorageLogger::new(100),
+        );
+        assert_eq!(logged_stream.stats().record_count(RecordKind::Open), 1);
+
+        logged_stream.write_all(&[0x01, 0x02, 0x03]).unwrap();
+        logged_stream.write_all(&[0x04, 0x05]).unwrap();
+        logged_stream.flush().unwrap();
+        logged_stream.get_mut().set_position(0);
+        let mut read = [0u8; 4];
+        logged_stream.read_exact(&mut read).unwrap();
+
+        let stats = logged_stream.stats();
+        assert!(logged_stream.get_log_records().is_empty());
+        assert_eq!(stats.record_count(RecordKind::Open), 1);
+        assert_eq!(stats.record_count(RecordKind::Write), 2);
+        assert_eq!(stats.record_count(RecordKind::Read), 1);
+        assert_eq!(stats.record_count(RecordKind::Flush), 1);
+        assert_eq!(stats.record_count(RecordKind::Error), 0);
+        assert_eq!(stats.record_count(RecordKind::Drop), 0);
+        assert_eq!(stats.bytes_written(), 5);
+        assert_eq!(stats.bytes_read(), 4);
+        assert_ne!(stats, Stats::default());
+    }
+
+    #[test]
+    fn test_byte_count_truncated() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            TruncatingFormatter::new(HexadecimalFormatter::new_default(), Some(2)),
+            RecordKindFilter::new(&[RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.write_all(&[0xff; 100]).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records[0].message, String::from("ff:ff … (+98 more bytes)"));
+        assert_eq!(records[0].byte_count, Some(100));
+    }
+
+    #[tokio::test]
+    async fn test_async_buf_read() {
+        use tokio::io::AsyncBufReadExt;
+
+        let mut logged_stream = LoggedStream::new(
+            tokio::io::BufReader::new(io::Cursor::new(b"first\nsecond\n".to_vec())),
+            DecimalFormatter::new(Some(" ")),
+            RecordKindFilter::new(&[RecordKind::Read]),
+            MemoryStorageLogger::new(100),
+        );
+
+        let mut line = String::new();
+        logged_stream.read_line(&mut line).await.unwrap();
+        assert_eq!(line, "first\n");
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].byte_count, Some(6));
+        assert_eq!(records[0].message, String::from("102 105 114 115 116 10"));
+
+        line.clear();
+        logged_stream.read_line(&mut line).await.unwrap();
+        assert_eq!(line, "second\n");
+        line.clear();
+        assert_eq!(logged_stream.read_line(&mut line).await.unwrap(), 0);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].byte_count, Some(7));
+        assert_eq!(logged_stream.stats().bytes_read(), 13);
+    }
+
+    /// Asynchronous buffered reader which counts calls of [`AsyncBufRead::poll_fill_buf`] method.
+    ///
+    /// [`AsyncBufRead::poll_fill_buf`]: tokio::io::AsyncBufRead::poll_fill_buf
+    struct CountingAsyncBufRead {
+        data: io::Cursor<Vec<u8>>,
+        poll_count: usize,
+    }
+
+    impl tokio::io::AsyncRead for CountingAsyncBufRead {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.get_mut().data).poll_read(cx, buf)
+        }
+    }
+
+    impl tokio::io::AsyncBufRead for CountingAsyncBufRead {
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+            let mut_self = self.get_mut();
+            mut_self.poll_count += 1;
+            Pin::new(&mut mut_self.data).poll_fill_buf(cx)
+        }
+
+        fn consume(self: Pin<&mut Self>, amount: usize) {
+            Pin::new(&mut self.get_mut().data).consume(amount)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_buf_read_consume_without_poll() {
+        use tokio::io::AsyncBufReadExt;
+
+        let mut logged_stream = LoggedStream::new(
+            CountingAsyncBufRead {
+                data: io::Cursor::new(vec![0x01, 0x02, 0x03, 0x04]),
+                poll_count: 0,
+            },
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read]),
+            MemoryStorageLogger::new(100),
+        );
+
+        assert_eq!(
+            logged_stream.fill_buf().await.unwrap(),
+            [0x01, 0x02, 0x03, 0x04]
+        );
+        Pin::new(&mut logged_stream).consume(3);
+        assert_eq!(logged_stream.fill_buf().await.unwrap(), [0x04]);
+        Pin::new(&mut logged_stream).consume(1);
+        assert_eq!(logged_stream.get_ref().poll_count, 2);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, String::from("01:02:03"));
+        assert_eq!(records[1].message, String::from("04"));
+    }
+
+    #[test]
+    fn test_buf_read() {
+        use std::io::BufRead;
+
+        let mut logged_stream = LoggedStream::new(
+            io::BufReader::new(io::Cursor::new(b"first\nsecond\nthird".to_vec())),
+            DecimalFormatter::new(Some(" ")),
+            RecordKindFilter::new(&[RecordKind::Read]),
+            MemoryStorageLogger::new(100),
+        );
+
+        // Repeated fill_buf calls without consuming don't produce log records.
+        assert_eq!(logged_stream.fill_buf().unwrap().len(), 18);
+        assert_eq!(logged_stream.fill_buf().unwrap().len(), 18);
+        assert!(logged_stream.get_log_records().is_empty());
+
+        let lines = (&mut logged_stream)
+            .lines()
+            .collect::<io::Result<Vec<String>>>()
+            .unwrap();
+        assert_eq!(lines, ["first", "second", "third"]);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].message, String::from("102 105 114 115 116 10"));
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.byte_count)
+                .collect::<Vec<_>>(),
+            [Some(6), Some(7), Some(5)]
+        );
+    }
+
+    /// Buffered reader which is not [`io::BufReader`] and counts calls of [`io::BufRead::fill_buf`] method.
+    struct CountingBufRead {
+        data: io::Cursor<Vec<u8>>,
+        fill_count: usize,
+    }
+
+    impl io::Read for CountingBufRead {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.data.read(buf)
+        }
+    }
+
+    impl io::BufRead for CountingBufRead {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            self.fill_count += 1;
+            self.data.fill_buf()
+        }
+
+        fn consume(&mut self, amount: usize) {
+            self.data.consume(amount)
         }
     }
+
+    #[test]
+    fn test_buf_read_consume_without_fill() {
+        use std::io::BufRead;
+
+        let mut logged_stream = LoggedStream::new(
+            CountingBufRead {
+                data: io::Cursor::new(vec![0x01, 0x02, 0x03, 0x04]),
+                fill_count: 0,
+            },
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read]),
+            MemoryStorageLogger::new(100),
+        );
+
+        assert_eq!(logged_stream.fill_buf().unwrap(), [0x01, 0x02, 0x03, 0x04]);
+        logged_stream.consume(3);
+        assert_eq!(logged_stream.fill_buf().unwrap(), [0x04]);
+        logged_stream.consume(1);
+        assert_eq!(logged_stream.get_ref().fill_count, 2);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, String::from("01:02:03"));
+        assert_eq!(records[1].message, String::from("04"));
+    }
+
+    #[test]
+    fn test_seek() {
+        use std::io::Seek;
+
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(vec![0u8; 10]),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Seek, RecordKind::Error]),
+            MemoryStorageLogger::new(100),
+        );
+
+        assert_eq!(logged_stream.seek(io::SeekFrom::Start(4)).unwrap(), 4);
+        assert_eq!(logged_stream.seek(io::SeekFrom::Current(3)).unwrap(), 7);
+        assert!(logged_stream.seek(io::SeekFrom::Current(-100)).is_err());
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].kind, RecordKind::Seek);
+        assert_eq!(records[0].message, String::from("Seek to 4."));
+        assert_eq!(records[1].message, String::from("Seek to 7."));
+        assert_eq!(records[2].kind, RecordKind::Error);
+        assert_eq!(logged_stream.stats().record_count(RecordKind::Seek), 2);
+    }
+
+    #[test]
+    fn test_error_details() {
+        struct FailingReader(Option<io::Error>);
+
+        impl io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(self
+                    .0
+                    .take()
+                    .unwrap_or_else(|| io::Error::from_raw_os_error(2)))
+            }
+        }
+
+        let mut logged_stream = LoggedStream::new(
+            FailingReader(Some(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "peer is gone",
+            ))),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        let mut buffer = [0u8; 8];
+        assert!(logged_stream.read(&mut buffer).is_err());
+        assert!(logged_stream.read(&mut buffer).is_err());
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].error_kind, None);
+        assert_eq!(records[0].os_error, None);
+        assert_eq!(records[1].kind, RecordKind::Error);
+        assert_eq!(records[1].message, "Error during read: peer is gone");
+        assert_eq!(records[1].error_kind, Some(io::ErrorKind::ConnectionReset));
+        assert_eq!(records[1].os_error, None);
+        assert_eq!(records[2].kind, RecordKind::Error);
+        assert!(records[2].message.starts_with("Error during read: "));
+        assert_eq!(records[2].error_kind, Some(io::ErrorKind::NotFound));
+        assert_eq!(records[2].os_error, Some(2));
+    }
+
+    #[test]
+    fn test_log_connect() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Connect]),
+            MemoryStorageLogger::new(100),
+        );
+        let address = std::net::SocketAddr::from(([127, 0, 0, 1], 8080));
+        logged_stream.log_connect(address);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Connect);
+        assert_eq!(
+            records[0].message,
+            String::from("Connected to 127.0.0.1:8080.")
+        );
+        assert_eq!(logged_stream.stats().record_count(RecordKind::Connect), 1);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_metrics() {
+        use metrics_util::debugging::DebugValue;
+        use metrics_util::debugging::DebuggingRecorder;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        metrics::with_local_recorder(&recorder, || {
+            let mut logged_stream = LoggedStream::new(
+                io::Cursor::new(vec![0x01, 0x02, 0x03]),
+                HexadecimalFormatter::new_default(),
+                DefaultFilter,
+                MemoryStorageLogger::new(10),
+            );
+            let mut buffer = [0u8; 3];
+            assert_eq!(logged_stream.read(&mut buffer).unwrap(), 3);
+            assert_eq!(logged_stream.write(&[0x04, 0x05]).unwrap(), 2);
+        });
+
+        let counter = |name: &str, kind: Option<&str>| {
+            snapshotter
+                .snapshot()
+                .into_vec()
+                .into_iter()
+                .find_map(|(key, _, _, value)| {
+                    let key = key.key();
+                    let kind_matches = key
+                        .labels()
+                        .all(|label| Some(label.value()) == kind && label.key() == "kind");
+                    match value {
+                        DebugValue::Counter(value) if key.name() == name && kind_matches => {
+                            Some(value)
+                        }
+                        _ => None,
+                    }
+                })
+        };
+        assert_eq!(counter("logged_stream_bytes_read_total", None), Some(3));
+        assert_eq!(counter("logged_stream_bytes_written_total", None), Some(2));
+        assert_eq!(
+            counter("logged_stream_records_total", Some("open")),
+            Some(1)
+        );
+        assert_eq!(
+            counter("logged_stream_records_total", Some("read")),
+            Some(1)
+        );
+        assert_eq!(
+            counter("logged_stream_records_total", Some("write")),
+            Some(1)
+        );
+        assert_eq!(
+            counter("logged_stream_records_total", Some("drop")),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_directional_formatter() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            DirectionalFormatter::new(
+                DecimalFormatter::new(Some(" ")),
+                HexadecimalFormatter::new(Some(" "), HexCase::Upper),
+            ),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.write_all(&[0x0a, 0xff]).unwrap();
+        logged_stream.get_mut().set_position(0);
+        let mut buffer = [0u8; 2];
+        logged_stream.read_exact(&mut buffer).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[0].message, String::from("0A FF"));
+        assert_eq!(records[1].kind, RecordKind::Read);
+        assert_eq!(records[1].message, String::from("10 255"));
+    }
+
+    #[tokio::test]
+    async fn test_format_buffer_with_kind() {
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+
+        /// Formatter which prefixes formatted buffer with glyph of log record kind.
+        struct GlyphFormatter;
+
+        impl BufferFormatter for GlyphFormatter {
+            fn get_separator(&self) -> &str {
+                ":"
+            }
+
+            fn format_byte(&self, byte: &u8) -> String {
+                format!("{byte:02x}")
+            }
+
+            fn format_buffer_with_kind(&self, kind: RecordKind, buffer: &[u8]) -> String {
+                format!("{kind}{}", self.format_buffer(buffer))
+            }
+        }
+
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut logged_stream = LoggedStream::new(
+            client,
+            GlyphFormatter,
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.write_all(&[0x01, 0x02]).await.unwrap();
+        server.write_all(&[0x03]).await.unwrap();
+        let mut buffer = [0u8; 1];
+        logged_stream.read_exact(&mut buffer).await.unwrap();
+
+        let mut sync_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            GlyphFormatter,
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+        io::Write::write_all(&mut sync_stream, &[0x04]).unwrap();
+        sync_stream.get_mut().set_position(0);
+        io::Read::read_exact(&mut sync_stream, &mut buffer).unwrap();
+
+        let messages = logged_stream
+            .get_log_records()
+            .into_iter()
+            .chain(sync_stream.get_log_records())
+            .map(|record| record.message)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, [">01:02", "<03", ">04", "<04"]);
+    }
+
+    #[test]
+    fn test_keep_raw() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+        assert_eq!(logged_stream.get_log_records()[0].message_bytes(), None);
+        logged_stream.clear_log_records();
+
+        let mut logged_stream = logged_stream.with_keep_raw(true);
+        logged_stream.write_all(&[0x03, 0x04, 0x05]).unwrap();
+        logged_stream.get_mut().set_position(0);
+        let mut buffer = [0u8; 5];
+        logged_stream.read_exact(&mut buffer).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Write);
+        assert_eq!(records[0].message_bytes(), Some(&[0x03, 0x04, 0x05][..]));
+        assert_eq!(records[0].byte_count, Some(3));
+        assert_eq!(records[1].kind, RecordKind::Read);
+        assert_eq!(
+            records[1].message_bytes(),
+            Some(&[0x01, 0x02, 0x03, 0x04, 0x05][..])
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        struct SlowReader;
+
+        impl io::Read for SlowReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                std::thread::sleep(std::time::Duration::from_millis(5));
+                buf[0] = 0x01;
+                Ok(1)
+            }
+        }
+
+        let mut logged_stream = LoggedStream::new(
+            SlowReader,
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        let mut buffer = [0u8; 1];
+        logged_stream.read_exact(&mut buffer).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_eq!(records[0].duration, None);
+        assert_eq!(records[1].kind, RecordKind::Read);
+        assert!(records[1].duration.unwrap() >= std::time::Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_set_enabled() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        assert!(logged_stream.is_enabled());
+
+        logged_stream.set_enabled(false);
+        assert!(!logged_stream.is_enabled());
+        logged_stream.write_all(&[0x01, 0x02]).unwrap();
+        logged_stream.flush().unwrap();
+
+        logged_stream.set_enabled(true);
+        logged_stream.write_all(&[0x03, 0x04]).unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_eq!(records[1].kind, RecordKind::Write);
+        assert_eq!(records[1].message, String::from("03:04"));
+        assert_eq!(logged_stream.stats().bytes_written(), 2);
+        assert_eq!(
+            logged_stream.get_ref().get_ref(),
+            &vec![0x01, 0x02, 0x03, 0x04]
+        );
+    }
+
+    #[test]
+    fn test_seq() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(1000),
+        );
+        for byte in 0..100u8 {
+            logged_stream.write_all(&[byte]).unwrap();
+        }
+        logged_stream.flush().unwrap();
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 102);
+        // Counter is shared with concurrently running tests, so sequence numbers may have gaps.
+        let seqs: Vec<u64> = records.iter().map(|record| record.seq.unwrap()).collect();
+        assert!(seqs.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    /// Writer which accepts every write and fails every flush.
+    struct FailingFlushWriter;
+
+    impl io::Write for FailingFlushWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+    }
+
+    impl tokio::io::AsyncWrite for FailingFlushWriter {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed")))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_flush_error() {
+        let mut logged_stream = LoggedStream::new(
+            FailingFlushWriter,
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Flush]),
+            MemoryStorageLogger::new(100),
+        );
+        assert!(logged_stream.flush().is_err());
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RecordKind::Error);
+        assert_eq!(
+            records[0].message,
+            String::from("Error during flush: closed")
+        );
+        assert_eq!(records[0].error_kind, Some(io::ErrorKind::BrokenPipe));
+        assert_eq!(logged_stream.stats().record_count(RecordKind::Flush), 0);
+    }
+
+    #[tokio::test]
+    async fn test_async_flush_error() {
+        use tokio::io::AsyncWriteExt;
+
+        let mut logged_stream = LoggedStream::new(
+            FailingFlushWriter,
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            MemoryStorageLogger::new(100),
+        );
+        assert!(AsyncWriteExt::flush(&mut logged_stream).await.is_err());
+
+        let records = logged_stream.get_log_records();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(kinds, [RecordKind::Open, RecordKind::Error]);
+        assert_eq!(
+            records[1].message,
+            String::from("Error during async flush: closed")
+        );
+    }
+
+    #[test]
+    fn test_partial_write() {
+        struct ShortWriter;
+
+        impl io::Write for ShortWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Ok(buf.len().min(2))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut logged_stream = LoggedStream::new(
+            ShortWriter,
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Write]),
+            MemoryStorageLogger::new(100),
+        );
+        assert_eq!(logged_stream.write(&[0x01, 0x02, 0x03, 0x04]).unwrap(), 2);
+        assert_eq!(logged_stream.write(&[0x05, 0x06]).unwrap(), 2);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, String::from("01:02 (partial 2/4)"));
+        assert_eq!(records[0].byte_count, Some(2));
+        assert_eq!(records[1].message, String::from("05:06"));
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(vec![0x01, 0x02, 0x03]),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Open, RecordKind::Read]),
+            MemoryStorageLogger::new(100),
+        )
+        .with_label(String::from("original"));
+        let mut buffer = [0u8; 1];
+        logged_stream.read_exact(&mut buffer).unwrap();
+
+        let mut cloned = logged_stream.clone();
+        assert_eq!(cloned.label(), Some("original"));
+        assert_eq!(cloned.get_ref().position(), 1);
+        assert_eq!(cloned.stats().record_count(RecordKind::Read), 0);
+
+        let mut buffer = [0u8; 2];
+        cloned.read_exact(&mut buffer).unwrap();
+        assert_eq!(buffer, [0x02, 0x03]);
+        assert_eq!(logged_stream.get_ref().position(), 1);
+
+        let messages = |records: collections::VecDeque<Record>| {
+            records
+                .into_iter()
+                .map(|record| record.message)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(messages(logged_stream.get_log_records()), ["Opened.", "01"]);
+        assert_eq!(
+            messages(cloned.get_log_records()),
+            ["Opened.", "01", "Opened.", "02:03"]
+        );
+    }
+
+    #[test]
+    fn test_log_eof() {
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(vec![0x01, 0x02]),
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Eof]),
+            MemoryStorageLogger::new(100),
+        )
+        .with_log_eof(true);
+        let mut buffer = Vec::new();
+        assert_eq!(logged_stream.read_to_end(&mut buffer).unwrap(), 2);
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, RecordKind::Read);
+        assert_eq!(records[0].message, String::from("01:02"));
+        assert_eq!(records[1].kind, RecordKind::Eof);
+        assert_eq!(records[1].message, String::from("Reached end of stream."));
+        assert_eq!(logged_stream.stats().record_count(RecordKind::Eof), 1);
+
+        // Reading into an empty buffer is not end of stream.
+        logged_stream.clear_log_records();
+        assert_eq!(logged_stream.read(&mut []).unwrap(), 0);
+        assert_eq!(logged_stream.get_log_records()[0].kind, RecordKind::Read);
+    }
+
+    #[tokio::test]
+    async fn test_log_eof_async() {
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+
+        let (client, mut server) = tokio::io::duplex(64);
+        let mut logged_stream = LoggedStream::new(
+            client,
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Eof]),
+            MemoryStorageLogger::new(100),
+        )
+        .with_log_eof(true);
+        server.write_all(&[0x01, 0x02]).await.unwrap();
+        drop(server);
+        let mut buffer = Vec::new();
+        assert_eq!(logged_stream.read_to_end(&mut buffer).await.unwrap(), 2);
+
+        let records = logged_stream.get_log_records();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(kinds, [RecordKind::Read, RecordKind::Eof]);
+        assert_eq!(records[0].message, String::from("01:02"));
+    }
+
+    #[tokio::test]
+    async fn test_trace_pending() {
+        use tokio::io::AsyncReadExt;
+
+        struct PendOnce {
+            pended: bool,
+        }
+
+        impl tokio::io::AsyncRead for PendOnce {
+            fn poll_read(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut tokio::io::ReadBuf<'_>,
+            ) -> Poll<io::Result<()>> {
+                if !self.pended {
+                    self.pended = true;
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                buf.put_slice(&[0x01, 0x02]);
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        let mut logged_stream = LoggedStream::new(
+            PendOnce { pended: false },
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Pending]),
+            MemoryStorageLogger::new(100),
+        )
+        .with_trace_pending(true);
+        let mut buffer = [0u8; 2];
+        logged_stream.read_exact(&mut buffer).await.unwrap();
+
+        let records = logged_stream.get_log_records();
+        let kinds = records.iter().map(|record| record.kind).collect::<Vec<_>>();
+        assert_eq!(kinds, [RecordKind::Pending, RecordKind::Read]);
+        assert_eq!(records[0].message, String::from("Pending read."));
+        assert_eq!(records[1].message, String::from("01:02"));
+
+        // Pending operations are not traced by default.
+        let mut logged_stream = LoggedStream::new(
+            PendOnce { pended: false },
+            HexadecimalFormatter::new_default(),
+            RecordKindFilter::new(&[RecordKind::Read, RecordKind::Pending]),
+            MemoryStorageLogger::new(100),
+        );
+        logged_stream.read_exact(&mut buffer).await.unwrap();
+        let kinds = logged_stream
+            .get_log_records()
+            .iter()
+            .map(|record| record.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [RecordKind::Read]);
+    }
+
+    #[test]
+    fn test_log_drop() {
+        let logger = ChannelLogger::new();
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            logger,
+        )
+        .with_log_drop(false);
+        let receiver = logged_stream.take_receiver_unchecked();
+        drop(logged_stream);
+
+        let kinds = receiver
+            .iter()
+            .map(|record| record.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [RecordKind::Open]);
+    }
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_clock() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let mut logged_stream = LoggedStreamBuilder::new(io::Cursor::new(Vec::<u8>::new()))
+            .logger(ChannelLogger::new())
+            .clock(FixedClock(time))
+            .build();
+        logged_stream.write_all(&[1, 2, 3]).unwrap();
+        let receiver = logged_stream.take_receiver_unchecked();
+        drop(logged_stream);
+
+        let records = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(records.len(), 3);
+        assert!(records.iter().all(|record| record.time == time));
+
+        let later = time + chrono::Duration::seconds(1);
+        let mut logged_stream = LoggedStream::new(
+            io::Cursor::new(Vec::<u8>::new()),
+            HexadecimalFormatter::new_default(),
+            DefaultFilter,
+            ChannelLogger::new(),
+        )
+        .with_clock(FixedClock(later));
+        logged_stream.write_all(&[1]).unwrap();
+        let receiver = logged_stream.take_receiver_unchecked();
+        drop(logged_stream);
+
+        let records = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(records[0].kind, RecordKind::Open);
+        assert_ne!(records[0].time, later);
+        assert!(records[1..].iter().all(|record| record.time == later));
+    }
+
+    #[tokio::test]
+    async fn test_clock_fill_buf_error() {
+        use tokio::io::AsyncBufReadExt;
+
+        struct FailingReader;
+
+        impl tokio::io::AsyncRead for FailingReader {
+            fn poll_read(
+                self: std::pin::Pin<&mut Self>,
+                _cx: &mut std::task::Context<'_>,
+                _buf: &mut tokio::io::ReadBuf<'_>,
+            ) -> std::task::Poll<io::Result<()>> {
+                std::task::Poll::Ready(Err(io::Error::from(io::ErrorKind::ConnectionReset)))
+            }
+        }
+
+        let time = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let mut logged_stream = LoggedStreamBuilder::new(tokio::io::BufReader::new(FailingReader))
+            .logger(MemoryStorageLogger::new(100))
+            .clock(FixedClock(time))
+            .build()
+            .with_label(String::from("peer"));
+        assert!(logged_stream.fill_buf().await.is_err());
+
+        let records = logged_stream.get_log_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].kind, RecordKind::Error);
+        assert_eq!(records[1].time, time);
+        assert_eq!(records[1].label.as_deref(), Some("peer"));
+        assert!(records[1].seq > records[0].seq);
+        assert_eq!(logged_stream.stats().record_count(RecordKind::Error), 1);
+    }
 }